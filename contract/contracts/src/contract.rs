@@ -1,5 +1,9 @@
 use soroban_sdk::{contract, contractimpl, token, Address, Env};
 
+use crate::errors::StakingError;
+use crate::events::{
+    EmergencyWithdrawn, RewardClaimed, RewardCompounded, Slashed, Staked, TierSet, Unstaked,
+};
 use crate::storage::*;
 use crate::types::{Config, Tier, UserInfo};
 
@@ -16,10 +20,10 @@ impl StakingContract {
         staking_token: Address,
         reward_token: Address,
         reward_rate: i128,
-    ) {
+    ) -> Result<(), StakingError> {
         // Prevent re-initialization
         if env.storage().instance().has(&crate::types::DataKey::Config) {
-            panic!("already initialized");
+            return Err(StakingError::AlreadyInitialized);
         }
 
         let config = Config {
@@ -31,10 +35,16 @@ impl StakingContract {
         write_config(&env, &config);
         write_last_update_time(&env, env.ledger().timestamp());
         extend_instance(&env);
+        Ok(())
     }
 
-    pub fn set_tier(env: Env, tier_id: u32, min_amount: i128, reward_multiplier: u32) {
-        let config = read_config(&env);
+    pub fn set_tier(
+        env: Env,
+        tier_id: u32,
+        min_amount: i128,
+        reward_multiplier: u32,
+    ) -> Result<(), StakingError> {
+        let config = read_config(&env)?;
         config.admin.require_auth();
 
         let tier = Tier {
@@ -43,17 +53,31 @@ impl StakingContract {
         };
         write_tier(&env, tier_id, &tier);
         extend_instance(&env);
+
+        TierSet {
+            tier_id,
+            min_amount,
+            reward_multiplier,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    pub fn stake(env: Env, user: Address, amount: i128, lock_duration: u64, tier_id: u32) {
+    pub fn stake(
+        env: Env,
+        user: Address,
+        amount: i128,
+        lock_duration: u64,
+        tier_id: u32,
+    ) -> Result<(), StakingError> {
         user.require_auth();
         if amount <= 0 {
-            panic!("amount must be > 0");
+            return Err(StakingError::InvalidAmount);
         }
 
-        update_reward(&env, Some(&user));
+        update_reward(&env, Some(&user))?;
 
-        let config = read_config(&env);
+        let config = read_config(&env)?;
 
         // Transfer staking tokens from user to contract
         let token_client = token::Client::new(&env, &config.staking_token);
@@ -78,7 +102,7 @@ impl StakingContract {
             reward_multiplier: 100,
         });
         if user_info.amount < tier.min_amount {
-            panic!("insufficient amount for tier");
+            return Err(StakingError::InsufficientAmountForTier);
         }
 
         // Boosting for long-term stakers: extra multiplier based on duration
@@ -103,27 +127,42 @@ impl StakingContract {
         write_total_shares(&env, total_shares);
 
         extend_instance(&env);
+
+        Staked {
+            user,
+            amount,
+            total_amount: user_info.amount,
+            shares: user_info.shares,
+            tier_id,
+            lock_duration,
+            reward_per_token_stored: read_reward_per_token_stored(&env),
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    pub fn claim(env: Env, user: Address, compound: bool) {
+    pub fn claim(env: Env, user: Address, compound: bool) -> Result<(), StakingError> {
         user.require_auth();
-        update_reward(&env, Some(&user));
+        if !has_user_info(&env, &user) {
+            return Err(StakingError::UserNotFound);
+        }
+        update_reward(&env, Some(&user))?;
 
-        let mut user_info = read_user_info(&env, &user).expect("user not found");
+        let mut user_info = read_user_info(&env, &user).ok_or(StakingError::UserNotFound)?;
         let reward = user_info.rewards;
 
         if reward > 0 {
             user_info.rewards = 0;
             write_user_info(&env, &user, &user_info);
 
-            let config = read_config(&env);
+            let config = read_config(&env)?;
             let reward_token = token::Client::new(&env, &config.reward_token);
 
             if compound {
                 // To compound, we would stake the reward. But reward token and staking token might differ.
                 // Assuming they are the same for compounding to work seamlessly, or they trade them if we had a dex.
                 if config.staking_token != config.reward_token {
-                    panic!("cannot compound: reward token differs from staking token");
+                    return Err(StakingError::CompoundTokenMismatch);
                 }
 
                 // Keep the reward in contract, just update shares and total shares
@@ -144,38 +183,58 @@ impl StakingContract {
                 let mut total_shares = read_total_shares(&env);
                 total_shares += diff_shares;
                 write_total_shares(&env, total_shares);
+
+                RewardCompounded {
+                    user: user.clone(),
+                    reward,
+                    total_amount: user_info.amount,
+                    shares: user_info.shares,
+                    reward_per_token_stored: user_info.reward_per_token_paid,
+                }
+                .publish(&env);
             } else {
                 reward_token.transfer(&env.current_contract_address(), &user, &reward);
+
+                RewardClaimed {
+                    user: user.clone(),
+                    reward,
+                    reward_per_token_stored: user_info.reward_per_token_paid,
+                }
+                .publish(&env);
             }
         }
         extend_instance(&env);
+        Ok(())
     }
 
-    pub fn unstake(env: Env, user: Address, amount: i128) {
+    pub fn unstake(env: Env, user: Address, amount: i128) -> Result<(), StakingError> {
         user.require_auth();
         if amount <= 0 {
-            panic!("amount must be > 0");
+            return Err(StakingError::InvalidAmount);
+        }
+        if !has_user_info(&env, &user) {
+            return Err(StakingError::UserNotFound);
         }
 
-        update_reward(&env, Some(&user));
+        update_reward(&env, Some(&user))?;
 
-        let mut user_info = read_user_info(&env, &user).expect("user not found");
+        let mut user_info = read_user_info(&env, &user).ok_or(StakingError::UserNotFound)?;
         if user_info.amount < amount {
-            panic!("insufficient balance");
+            return Err(StakingError::InsufficientBalance);
         }
 
-        let mut actual_amount = amount;
+        let mut penalty = 0;
         let current_time = env.ledger().timestamp();
 
         // Early withdrawal penalty
         if current_time < user_info.lock_start_time + user_info.lock_duration {
             // Apply 20% penalty
-            let penalty = (amount * 20) / 100;
-            actual_amount = amount - penalty;
+            penalty = (amount * 20) / 100;
             // Penalty remains in contract or burned, here we just don't send it to the user.
         }
+        let actual_amount = amount - penalty;
 
-        let config = read_config(&env);
+        let config = read_config(&env)?;
 
         user_info.amount -= amount;
 
@@ -210,17 +269,32 @@ impl StakingContract {
         let token_client = token::Client::new(&env, &config.staking_token);
         token_client.transfer(&env.current_contract_address(), &user, &actual_amount);
         extend_instance(&env);
+
+        Unstaked {
+            user,
+            amount,
+            penalty,
+            total_amount: user_info.amount,
+            shares: user_info.shares,
+            tier_id: user_info.tier_id,
+            reward_per_token_stored: user_info.reward_per_token_paid,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    pub fn slash(env: Env, user: Address, amount: i128) {
-        let config = read_config(&env);
+    pub fn slash(env: Env, user: Address, amount: i128) -> Result<(), StakingError> {
+        let config = read_config(&env)?;
         config.admin.require_auth();
 
-        update_reward(&env, Some(&user));
+        if !has_user_info(&env, &user) {
+            return Err(StakingError::UserNotFound);
+        }
+        update_reward(&env, Some(&user))?;
 
-        let mut user_info = read_user_info(&env, &user).expect("user not found");
+        let mut user_info = read_user_info(&env, &user).ok_or(StakingError::UserNotFound)?;
         if user_info.amount < amount {
-            panic!("slash amount exceeds balance");
+            return Err(StakingError::SlashExceedsBalance);
         }
 
         user_info.amount -= amount;
@@ -252,22 +326,33 @@ impl StakingContract {
 
         // Slashed tokens stay in contract or could be burned.
         extend_instance(&env);
+
+        Slashed {
+            user,
+            amount,
+            total_amount: user_info.amount,
+            shares: user_info.shares,
+            tier_id: user_info.tier_id,
+            reward_per_token_stored: user_info.reward_per_token_paid,
+        }
+        .publish(&env);
+        Ok(())
     }
 
-    pub fn emergency_withdraw(env: Env, user: Address) {
+    pub fn emergency_withdraw(env: Env, user: Address) -> Result<(), StakingError> {
         user.require_auth();
 
         // Skips reward update! Just get funds out minus 20% penalty.
-        let user_info = read_user_info(&env, &user).expect("user not found");
+        let user_info = read_user_info(&env, &user).ok_or(StakingError::UserNotFound)?;
         let amount = user_info.amount;
         if amount == 0 {
-            panic!("no balance");
+            return Err(StakingError::NoBalance);
         }
 
         let penalty = (amount * 20) / 100;
         let actual_amount = amount - penalty;
 
-        let config = read_config(&env);
+        let config = read_config(&env)?;
         let token_client = token::Client::new(&env, &config.staking_token);
 
         let mut total_shares = read_total_shares(&env);
@@ -287,11 +372,22 @@ impl StakingContract {
 
         token_client.transfer(&env.current_contract_address(), &user, &actual_amount);
         extend_instance(&env);
+
+        // Emergency exits skip the reward update, so report the accumulator as
+        // last settled for this user rather than the current global value.
+        EmergencyWithdrawn {
+            user,
+            amount,
+            penalty,
+            reward_per_token_stored: user_info.reward_per_token_paid,
+        }
+        .publish(&env);
+        Ok(())
     }
 }
 
-fn update_reward(env: &Env, user: Option<&Address>) {
-    let config = read_config(env);
+fn update_reward(env: &Env, user: Option<&Address>) -> Result<(), StakingError> {
+    let config = read_config(env)?;
     let mut rpt_stored = read_reward_per_token_stored(env);
     let last_update_time = read_last_update_time(env);
     let current_time = env.ledger().timestamp();
@@ -324,4 +420,5 @@ fn update_reward(env: &Env, user: Option<&Address>) {
         user_info.reward_per_token_paid = rpt_stored;
         write_user_info(env, u, &user_info);
     }
+    Ok(())
 }
@@ -0,0 +1,16 @@
+use soroban_sdk::contracterror;
+
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum StakingError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    InvalidAmount = 3,
+    InsufficientAmountForTier = 4,
+    CompoundTokenMismatch = 5,
+    UserNotFound = 6,
+    InsufficientBalance = 7,
+    SlashExceedsBalance = 8,
+    NoBalance = 9,
+}
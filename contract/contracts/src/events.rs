@@ -0,0 +1,81 @@
+use soroban_sdk::{contractevent, Address};
+
+// Each user event carries the resulting position and the accumulator it was
+// settled against, so an indexer can rebuild a position without reading storage.
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierSet {
+    #[topic]
+    pub tier_id: u32,
+    pub min_amount: i128,
+    pub reward_multiplier: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Staked {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub total_amount: i128,
+    pub shares: i128,
+    pub tier_id: u32,
+    pub lock_duration: u64,
+    pub reward_per_token_stored: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Unstaked {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub penalty: i128,
+    pub total_amount: i128,
+    pub shares: i128,
+    pub tier_id: u32,
+    pub reward_per_token_stored: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardClaimed {
+    #[topic]
+    pub user: Address,
+    pub reward: i128,
+    pub reward_per_token_stored: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RewardCompounded {
+    #[topic]
+    pub user: Address,
+    pub reward: i128,
+    pub total_amount: i128,
+    pub shares: i128,
+    pub reward_per_token_stored: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Slashed {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub total_amount: i128,
+    pub shares: i128,
+    pub tier_id: u32,
+    pub reward_per_token_stored: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EmergencyWithdrawn {
+    #[topic]
+    pub user: Address,
+    pub amount: i128,
+    pub penalty: i128,
+    pub reward_per_token_stored: i128,
+}
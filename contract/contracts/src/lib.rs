@@ -1,6 +1,8 @@
 #![no_std]
 
 pub mod contract;
+pub mod errors;
+pub mod events;
 pub mod storage;
 pub mod types;
 
@@ -1,3 +1,4 @@
+use crate::errors::StakingError;
 use crate::types::{Config, DataKey, Tier, UserInfo};
 use soroban_sdk::{Address, Env};
 
@@ -10,8 +11,11 @@ pub fn extend_instance(env: &Env) {
         .extend_ttl(TTL_INSTANCE, TTL_INSTANCE);
 }
 
-pub fn read_config(env: &Env) -> Config {
-    env.storage().instance().get(&DataKey::Config).unwrap()
+pub fn read_config(env: &Env) -> Result<Config, StakingError> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Config)
+        .ok_or(StakingError::NotInitialized)
 }
 
 pub fn write_config(env: &Env, config: &Config) {
@@ -48,6 +52,12 @@ pub fn read_user_info(env: &Env, user: &Address) -> Option<UserInfo> {
     val
 }
 
+pub fn has_user_info(env: &Env, user: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::UserInfo(user.clone()))
+}
+
 pub fn write_user_info(env: &Env, user: &Address, info: &UserInfo) {
     let key = DataKey::UserInfo(user.clone());
     env.storage().persistent().set(&key, info);
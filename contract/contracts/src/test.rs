@@ -1,9 +1,11 @@
 #![cfg(test)]
 
 use crate::contract::{StakingContract, StakingContractClient};
+use crate::errors::StakingError;
+use crate::events::{RewardClaimed, Staked, Unstaked};
 use soroban_sdk::{
-    testutils::{Address as _, Ledger},
-    token, Address, Env,
+    testutils::{Address as _, Events, Ledger},
+    token, vec, Address, Env, Event,
 };
 
 fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
@@ -11,6 +13,14 @@ fn create_token_contract<'a>(env: &Env, admin: &Address) -> token::Client<'a> {
     token::Client::new(env, &contract_id.address())
 }
 
+fn assert_last_event(env: &Env, contract: &Address, event: impl Event) {
+    let last = env.events().all().last().unwrap();
+    assert_eq!(
+        vec![env, last],
+        vec![env, (contract.clone(), event.topics(env), event.data(env))]
+    );
+}
+
 #[test]
 fn test_staking_lifecycle() {
     let env = Env::default();
@@ -79,3 +89,133 @@ fn test_staking_lifecycle() {
     // Has 998_900. Now has 998_900 + 400 = 999_300.
     assert_eq!(token.balance(&user1), 999_300);
 }
+
+#[test]
+fn test_error_codes() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+    let user2 = Address::generate(&env);
+
+    let token = create_token_contract(&env, &admin);
+    let reward_token = create_token_contract(&env, &admin);
+    let token_admin = token::StellarAssetClient::new(&env, &token.address);
+    token_admin.mint(&user1, &1_000_000);
+
+    let contract_id = env.register(StakingContract, ());
+    let client = StakingContractClient::new(&env, &contract_id);
+
+    // Nothing works before initialization
+    assert_eq!(
+        client.try_stake(&user1, &100, &0, &0),
+        Err(Ok(StakingError::NotInitialized))
+    );
+
+    client.initialize(&admin, &token.address, &reward_token.address, &10);
+    assert_eq!(
+        client.try_initialize(&admin, &token.address, &reward_token.address, &10),
+        Err(Ok(StakingError::AlreadyInitialized))
+    );
+
+    client.set_tier(&1, &1000, &150);
+    assert_eq!(
+        client.try_stake(&user1, &0, &0, &0),
+        Err(Ok(StakingError::InvalidAmount))
+    );
+    assert_eq!(
+        client.try_stake(&user1, &500, &0, &1),
+        Err(Ok(StakingError::InsufficientAmountForTier))
+    );
+    assert_eq!(
+        client.try_claim(&user2, &false),
+        Err(Ok(StakingError::UserNotFound))
+    );
+    assert_eq!(
+        client.try_emergency_withdraw(&user2),
+        Err(Ok(StakingError::UserNotFound))
+    );
+
+    client.stake(&user1, &500, &0, &0);
+    assert_eq!(
+        client.try_unstake(&user1, &600),
+        Err(Ok(StakingError::InsufficientBalance))
+    );
+    assert_eq!(
+        client.try_slash(&user1, &600),
+        Err(Ok(StakingError::SlashExceedsBalance))
+    );
+
+    let mut ledger = env.ledger().get();
+    ledger.timestamp += 10;
+    env.ledger().set(ledger);
+    assert_eq!(
+        client.try_claim(&user1, &true),
+        Err(Ok(StakingError::CompoundTokenMismatch))
+    );
+}
+
+#[test]
+fn test_position_events() {
+    let env = Env::default();
+    env.mock_all_auths();
+
+    let admin = Address::generate(&env);
+    let user1 = Address::generate(&env);
+
+    let token = create_token_contract(&env, &admin);
+    let token_admin = token::StellarAssetClient::new(&env, &token.address);
+    token_admin.mint(&user1, &1_000_000);
+
+    let contract_id = env.register(StakingContract, ());
+    let client = StakingContractClient::new(&env, &contract_id);
+    client.initialize(&admin, &token.address, &token.address, &10);
+
+    client.stake(&user1, &1000, &0, &0);
+    assert_last_event(
+        &env,
+        &contract_id,
+        Staked {
+            user: user1.clone(),
+            amount: 1000,
+            total_amount: 1000,
+            shares: 1000,
+            tier_id: 0,
+            lock_duration: 0,
+            reward_per_token_stored: 0,
+        },
+    );
+
+    // 10s at 10 tokens/s over 1000 shares
+    let mut ledger = env.ledger().get();
+    ledger.timestamp += 10;
+    env.ledger().set(ledger);
+    token_admin.mint(&contract_id, &100_000);
+
+    client.claim(&user1, &false);
+    assert_last_event(
+        &env,
+        &contract_id,
+        RewardClaimed {
+            user: user1.clone(),
+            reward: 100,
+            reward_per_token_stored: 100_000_000,
+        },
+    );
+
+    client.unstake(&user1, &400);
+    assert_last_event(
+        &env,
+        &contract_id,
+        Unstaked {
+            user: user1,
+            amount: 400,
+            penalty: 0,
+            total_amount: 600,
+            shares: 600,
+            tier_id: 0,
+            reward_per_token_stored: 100_000_000,
+        },
+    );
+}
@@ -0,0 +1,27 @@
+use soroban_sdk::{xdr::ToXdr, Address, Bytes, BytesN, Env, Vec};
+
+// Leaves commit to the buyer and how many tickets they may buy:
+// sha256(address_xdr || allocation as big-endian u32). An allocation of 0
+// places no per-leaf limit on the buyer.
+pub fn leaf(e: &Env, buyer: &Address, allocation: u32) -> BytesN<32> {
+    let mut data = buyer.clone().to_xdr(e);
+    data.extend_from_array(&allocation.to_be_bytes());
+    e.crypto().sha256(&data).to_bytes()
+}
+
+// Walks the proof from `leaf` up to the root. Pairs are sorted before hashing,
+// so proofs carry no left/right flags.
+pub fn verify(e: &Env, root: &BytesN<32>, leaf: BytesN<32>, proof: &Vec<BytesN<32>>) -> bool {
+    let mut node = leaf;
+    for sibling in proof.iter() {
+        node = hash_pair(e, &node, &sibling);
+    }
+    node == *root
+}
+
+pub fn hash_pair(e: &Env, a: &BytesN<32>, b: &BytesN<32>) -> BytesN<32> {
+    let (first, second) = if a <= b { (a, b) } else { (b, a) };
+    let mut data = Bytes::from(first);
+    data.append(&Bytes::from(second));
+    e.crypto().sha256(&data).to_bytes()
+}
@@ -0,0 +1,70 @@
+use soroban_sdk::contracterror;
+
+// Codes are part of the public interface consumed by the backend and frontend,
+// so existing values must never be renumbered; new variants go at the end.
+#[contracterror]
+#[derive(Copy, Clone, Debug, Eq, PartialEq, PartialOrd, Ord)]
+#[repr(u32)]
+pub enum TicketError {
+    AlreadyInitialized = 1,
+    NotInitialized = 2,
+    TierAlreadyExists = 3,
+    TierNotFound = 4,
+    TierNotActive = 5,
+    TierSoldOut = 6,
+    ExceedsMaxSupply = 7,
+    TicketNotFound = 8,
+    NotTicketOwner = 9,
+    RefundWindowClosed = 10,
+    TicketInvalidated = 11,
+    NonTransferable = 12,
+    ApprovalDisabled = 13,
+    InvalidEventWindow = 14,
+    UnauthorizedScanner = 15,
+    AlreadyCheckedIn = 16,
+    CheckInClosed = 17,
+    Unauthorized = 18,
+    SettlementLocked = 19,
+    NothingToSettle = 20,
+    InsufficientEscrow = 21,
+    PaymentTokenNotAccepted = 22,
+    EventCancelled = 23,
+    TierRetired = 24,
+    MaxSupplyBelowMinted = 25,
+    InvalidSaleWindow = 26,
+    SaleNotStarted = 27,
+    SaleEnded = 28,
+    NoActiveSalePhase = 29,
+    PhaseSoldOut = 30,
+    InvalidPricingCurve = 31,
+    WalletLimitReached = 32,
+    AllowlistRequired = 33,
+    AllowlistNotSet = 34,
+    InvalidProof = 35,
+    AllocationExhausted = 36,
+    VoucherSignerNotSet = 37,
+    VoucherExpired = 38,
+    VoucherAlreadyUsed = 39,
+    InvalidQuantity = 40,
+    InvalidRefundPolicy = 41,
+    InvalidResalePolicy = 42,
+    ResaleDisabled = 43,
+    ResalePriceTooHigh = 44,
+    ListingNotFound = 45,
+    TicketNotResellable = 46,
+    InvalidUpgrade = 47,
+    InvalidPromoCode = 48,
+    PromoCodeNotFound = 49,
+    PromoCodeExpired = 50,
+    PromoCodeNotApplicable = 51,
+    PromoCodeExhausted = 52,
+    MetadataUriTooLong = 53,
+    SeatSelectionRequired = 54,
+    SeatNotFound = 55,
+    SeatTaken = 56,
+    SeatAlreadyExists = 57,
+    InvalidPrice = 58,
+    EventEnded = 59,
+    GiftedTicket = 60,
+    SeatedAllowlist = 61,
+}
@@ -0,0 +1,308 @@
+use soroban_sdk::{contractevent, Address, BytesN, String, Symbol, Vec};
+
+use crate::pricing::PricingCurve;
+use crate::storage_types::{CancellationFee, PromoCode, RefundStep, SalePhase, Seat};
+
+// Contract-wide events: event setup, policies, promo codes and payouts
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Initialized {
+    #[topic]
+    pub admin: Address,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub refund_cutoff_time: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EventCancelled {
+    #[topic]
+    pub caller: Address,
+    pub cancelled_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReentryPolicySet {
+    pub allow_reentry: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct WalletCapSet {
+    pub max_per_wallet: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundPolicySet {
+    pub schedule: Vec<RefundStep>,
+    pub cancellation_fee: Option<CancellationFee>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResalePolicySet {
+    pub enabled: bool,
+    pub max_price_bps: u32,
+    pub royalty_bps: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCodeSet {
+    #[topic]
+    pub code_hash: BytesN<32>,
+    pub promo: PromoCode,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCodeRemoved {
+    #[topic]
+    pub code_hash: BytesN<32>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct VoucherSignerSet {
+    pub public_key: Option<BytesN<32>>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PayoutAddressSet {
+    #[topic]
+    pub caller: Address,
+    pub payout: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EscrowSettled {
+    #[topic]
+    pub payment_token: Address,
+    pub payout: Address,
+    pub amount: i128,
+}
+
+// Every tier and ticket event carries the tier symbol as its first topic after
+// the event name so indexers can subscribe to a single tier of a single event
+// contract.
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierCreated {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub name: String,
+    pub base_price: i128,
+    pub max_supply: u32,
+    pub pricing: PricingCurve,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierUpdated {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub name: String,
+    pub reference_price: i128,
+    pub max_supply: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierStatusChanged {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub active: bool,
+    pub retired: bool,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SaleScheduleSet {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub sale_start: u64,
+    pub sale_end: u64,
+    pub phases: Vec<SalePhase>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AllowlistRootSet {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub root: Option<BytesN<32>>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierMetadataUriSet {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub uri: Option<String>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatsAdded {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub seats: Vec<Seat>,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierWalletCapSet {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub max_per_wallet: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierPriceSet {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub payment_token: Address,
+    pub base_price: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TierPriceRemoved {
+    #[topic]
+    pub tier_symbol: Symbol,
+    pub payment_token: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketPurchased {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub buyer: Address,
+    pub token_id: u32,
+    pub price: i128,
+    pub payment_token: Address,
+    pub payer: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketMinted {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub to: Address,
+    pub token_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketRefunded {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub owner: Address,
+    pub token_id: u32,
+    pub amount: i128,
+    pub payment_token: Address,
+    pub refunded_to: Address,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketBurned {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub owner: Address,
+    pub token_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketCheckedIn {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub scanner: Address,
+    pub token_id: u32,
+    pub checked_in_at: u64,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketListed {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub seller: Address,
+    pub token_id: u32,
+    pub price: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ListingCancelled {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub seller: Address,
+    pub token_id: u32,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketResold {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub seller: Address,
+    pub buyer: Address,
+    pub token_id: u32,
+    pub price: i128,
+    pub royalty: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketUpgraded {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub owner: Address,
+    pub token_id: u32,
+    pub from_tier: Symbol,
+    pub amount_paid: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCodeRedeemed {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub buyer: Address,
+    pub code_hash: BytesN<32>,
+    pub token_id: u32,
+    pub discount: i128,
+}
+
+#[contractevent]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SeatAssigned {
+    #[topic]
+    pub tier_symbol: Symbol,
+    #[topic]
+    pub owner: Address,
+    pub token_id: u32,
+    pub seat: Seat,
+}
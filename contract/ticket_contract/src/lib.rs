@@ -3,21 +3,62 @@
 #[cfg(test)]
 mod test;
 
-use soroban_sdk::{contract, contractimpl, contracttype, token, Address, Env, String, Symbol, Vec};
-use stellar_access::ownable::{self as ownable, Ownable};
-use stellar_tokens::non_fungible::{Base, NonFungibleToken};
+use soroban_sdk::{
+    contract, contractimpl, panic_with_error, symbol_short, token, xdr::ToXdr, Address, BytesN,
+    Env, IntoVal, String, Symbol, TryFromVal, Val, Vec,
+};
+use stellar_access::access_control::{self as access_control, AccessControl};
+use stellar_access::ownable::Ownable;
+use stellar_tokens::non_fungible::{Base, NFTStorageKey, NonFungibleToken};
 
+mod allowlist;
+mod errors;
+mod events;
+mod metadata;
+mod pricing;
 mod storage_types;
-use storage_types::{DataKey, EventInfo, Ticket, Tier};
+pub use errors::TicketError;
+use events::{
+    AllowlistRootSet, EscrowSettled, EventCancelled, Initialized, ListingCancelled,
+    PayoutAddressSet, PromoCodeRedeemed, PromoCodeRemoved, PromoCodeSet, ReentryPolicySet,
+    RefundPolicySet, ResalePolicySet, SaleScheduleSet, SeatAssigned, SeatsAdded, TicketBurned,
+    TicketCheckedIn, TicketListed, TicketMinted, TicketPurchased, TicketRefunded, TicketResold,
+    TicketUpgraded, TierCreated, TierMetadataUriSet, TierPriceRemoved, TierPriceSet,
+    TierStatusChanged, TierUpdated, TierWalletCapSet, VoucherSignerSet, WalletCapSet,
+};
+use pricing::{PricingContext, PricingCurve};
+use storage_types::{
+    CancellationFee, DataKey, Discount, EventInfo, Listing, PromoCode, RefundStep, ResalePolicy,
+    SalePhase, Seat, Ticket, TicketUpgrade, Tier, Voucher,
+};
 
-// Dynamic pricing constants
-const PRICE_INCREASE_BPS: i128 = 500; // 5% increase per tier threshold
+// Doors open this long before the event starts
+const CHECK_IN_OPENS_BEFORE: u64 = 4 * 60 * 60;
+
+// Storage TTLs, in ledgers of roughly LEDGER_SECONDS each
+const LEDGER_SECONDS: u64 = 5;
+const TTL_PERSISTENT: u32 = 17280 * 90; // 90 days
+
+// Upper bound on items returned by one paginated view. Index and seat pages
+// read one ledger entry per item, so this stays well under the per-transaction
+// entry limit.
+const MAX_PAGE_SIZE: u32 = 50;
+
+// Proceeds stay in escrow this long after the event ends
+const DISPUTE_WINDOW: u64 = 7 * 24 * 60 * 60;
+
+// Access control roles. The admin implicitly holds all of them.
+const ORGANIZER: Symbol = symbol_short!("organizer");
+const FINANCE: Symbol = symbol_short!("finance");
+const SCANNER: Symbol = symbol_short!("scanner");
+const MINTER: Symbol = symbol_short!("minter");
 
 #[contract]
 pub struct SoulboundTicketContract;
 
 #[contractimpl]
 impl SoulboundTicketContract {
+    #[allow(clippy::too_many_arguments)]
     pub fn initialize(
         e: &Env,
         admin: Address,
@@ -25,288 +66,2129 @@ impl SoulboundTicketContract {
         symbol: String,
         uri: String,
         start_time: u64,
+        end_time: u64,
         refund_cutoff_time: u64,
-    ) {
-        if e.storage().instance().has(&DataKey::Admin) {
-            panic!("already initialized");
+    ) -> Result<(), TicketError> {
+        if e.storage().instance().has(&DataKey::EventInfo) {
+            return Err(TicketError::AlreadyInitialized);
+        }
+        if end_time < start_time {
+            return Err(TicketError::InvalidEventWindow);
         }
 
         // Init Event Info
         let event_info = EventInfo {
             start_time,
+            end_time,
             refund_cutoff_time,
+            allow_reentry: false,
+            cancelled: false,
+            max_per_wallet: 0,
+            refund_schedule: Vec::new(e),
+            cancellation_fee: None,
         };
         e.storage().instance().set(&DataKey::EventInfo, &event_info);
-        e.storage().instance().set(&DataKey::Admin, &admin);
-
-        // Init Token Counter
-        e.storage().instance().set(&DataKey::TokenIdCounter, &0u32);
+        e.storage().instance().set(&DataKey::Payout, &admin);
+        extend_instance(e, &event_info);
 
         // Init Token Metadata via OpenZeppelin Base
         Base::set_metadata(e, uri, name, symbol);
-        ownable::set_owner(e, &admin);
+        // Ownership is the access control admin, see the Ownable impl below
+        access_control::set_admin(e, &admin);
+
+        Initialized {
+            admin,
+            start_time,
+            end_time,
+            refund_cutoff_time,
+        }
+        .publish(e);
+        Ok(())
     }
 
     // Add a new ticket tier
-    pub fn add_tier(e: &Env, tier_symbol: Symbol, name: String, base_price: i128, max_supply: u32) {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    pub fn add_tier(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        name: String,
+        base_price: i128,
+        max_supply: u32,
+        pricing: PricingCurve,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
 
         let key = DataKey::Tier(tier_symbol.clone());
         if e.storage().persistent().has(&key) {
-            panic!("Tier already exists");
+            return Err(TicketError::TierAlreadyExists);
+        }
+        if !pricing.is_valid() {
+            return Err(TicketError::InvalidPricingCurve);
+        }
+        if base_price < 0 {
+            return Err(TicketError::InvalidPrice);
         }
 
         let tier = Tier {
-            name,
+            name: name.clone(),
             base_price,
             current_price: base_price,
             max_supply,
             minted: 0,
             active: true,
+            retired: false,
+            sale_start: 0,
+            sale_end: 0,
+            phases: Vec::new(e),
+            pricing: pricing.clone(),
+            max_per_wallet: 0,
+            metadata_uri: None,
         };
 
         e.storage().persistent().set(&key, &tier);
+        extend_persistent(e, &key);
+        let mut tiers = read_tier_list(e);
+        tiers.push_back(tier_symbol.clone());
+        set_persistent(e, &DataKey::TierList, &tiers);
+
+        TierCreated {
+            tier_symbol,
+            name,
+            base_price,
+            max_supply,
+            pricing,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Fix a tier's name, reference price or supply. Supply can never drop below minted.
+    // The reference price only feeds `current_price`; buyers pay the per-token
+    // prices set with `set_accepted_token`.
+    pub fn update_tier(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        name: String,
+        reference_price: i128,
+        max_supply: u32,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut tier = read_tier(e, &tier_symbol)?;
+        if tier.retired {
+            return Err(TicketError::TierRetired);
+        }
+        if max_supply < tier.minted {
+            return Err(TicketError::MaxSupplyBelowMinted);
+        }
+        if reference_price < 0 {
+            return Err(TicketError::InvalidPrice);
+        }
+
+        tier.name = name.clone();
+        tier.base_price = reference_price;
+        tier.max_supply = max_supply;
+        write_tier(e, &tier_symbol, &mut tier);
+
+        TierUpdated {
+            tier_symbol,
+            name,
+            reference_price,
+            max_supply,
+        }
+        .publish(e);
+        Ok(())
     }
 
-    // Dynamic pricing query
-    pub fn get_ticket_price(e: &Env, tier_symbol: Symbol) -> i128 {
-        let key = DataKey::Tier(tier_symbol);
-        let tier: Tier = e.storage().persistent().get(&key).unwrap();
-        // Dynamic pricing logic: base_price * (1 + (minted / (max_supply / 5)) * 5%)
-        let thresholds_passed = tier.minted / (tier.max_supply.max(1) / 5).max(1);
-        let increase = tier.base_price * PRICE_INCREASE_BPS * (thresholds_passed as i128) / 10000;
-        tier.base_price + increase
+    // Pause or resume sales of a tier
+    pub fn set_tier_active(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        active: bool,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut tier = read_tier(e, &tier_symbol)?;
+        if tier.retired {
+            return Err(TicketError::TierRetired);
+        }
+        tier.active = active;
+        write_tier(e, &tier_symbol, &mut tier);
+
+        TierStatusChanged {
+            tier_symbol,
+            active,
+            retired: false,
+        }
+        .publish(e);
+        Ok(())
     }
 
-    // Batch Minting for Organizer
-    pub fn batch_mint(e: &Env, to: Address, tier_symbol: Symbol, amount: u32) {
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        admin.require_auth();
+    // Permanently stop selling a tier. Tickets already sold stay valid.
+    pub fn retire_tier(e: &Env, caller: Address, tier_symbol: Symbol) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
 
-        let key = DataKey::Tier(tier_symbol.clone());
-        let mut tier: Tier = e
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| panic!("Tier not found"));
+        let mut tier = read_tier(e, &tier_symbol)?;
+        if tier.retired {
+            return Err(TicketError::TierRetired);
+        }
+        tier.active = false;
+        tier.retired = true;
+        write_tier(e, &tier_symbol, &mut tier);
+
+        TierStatusChanged {
+            tier_symbol,
+            active: false,
+            retired: true,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Schedule when a tier is on sale. `sale_end` of 0 leaves the sale open-ended.
+    // Phases must be ordered and non-overlapping; while a tier has phases it only
+    // sells inside one, at the phase's price and up to its allocation. Sales
+    // carry over to the phase of the same name, so editing a running phase
+    // does not hand out its allocation again.
+    pub fn set_sale_schedule(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        sale_start: u64,
+        sale_end: u64,
+        phases: Vec<SalePhase>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut tier = read_tier(e, &tier_symbol)?;
+        if sale_end != 0 && sale_end <= sale_start {
+            return Err(TicketError::InvalidSaleWindow);
+        }
+
+        let mut checked = Vec::new(e);
+        let mut previous_end = sale_start;
+        for mut phase in phases.iter() {
+            if phase.start_time < previous_end || phase.end_time <= phase.start_time {
+                return Err(TicketError::InvalidSaleWindow);
+            }
+            if sale_end != 0 && phase.end_time > sale_end {
+                return Err(TicketError::InvalidSaleWindow);
+            }
+            previous_end = phase.end_time;
+            phase.sold = tier
+                .phases
+                .iter()
+                .find(|existing| existing.name == phase.name)
+                .map_or(0, |existing| existing.sold);
+            checked.push_back(phase);
+        }
+
+        tier.sale_start = sale_start;
+        tier.sale_end = sale_end;
+        tier.phases = checked;
+        write_tier(e, &tier_symbol, &mut tier);
+
+        SaleScheduleSet {
+            tier_symbol,
+            sale_start,
+            sale_end,
+            phases: tier.phases,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Point a tier's tickets at their own image/metadata location, or back to
+    // the collection base URI with `None`
+    pub fn set_tier_metadata_uri(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        uri: Option<String>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        if let Some(uri) = &uri {
+            if uri.len() > metadata::MAX_METADATA_URI_LEN {
+                return Err(TicketError::MetadataUriTooLong);
+            }
+        }
+
+        let mut tier = read_tier(e, &tier_symbol)?;
+        tier.metadata_uri = uri.clone();
+        write_tier(e, &tier_symbol, &mut tier);
+
+        TierMetadataUriSet { tier_symbol, uri }.publish(e);
+        Ok(())
+    }
+
+    // Tier symbols in creation order, including retired tiers
+    pub fn list_tiers(e: &Env, start: u32, limit: u32) -> Vec<Symbol> {
+        page(&read_tier_list(e), start, limit)
+    }
+
+    // Ids of the live tickets a wallet holds. Removing a ticket moves the
+    // wallet's last one into its place, so the order is not stable.
+    pub fn tickets_of(e: &Env, owner: Address, start: u32, limit: u32) -> Vec<u32> {
+        index_page(e, &TicketIndex::Owner(owner), start, limit)
+    }
+
+    // Ids of the live tickets in a tier, in the same slot order as `tickets_of`
+    pub fn tickets_by_tier(e: &Env, tier_symbol: Symbol, start: u32, limit: u32) -> Vec<u32> {
+        index_page(e, &TicketIndex::Tier(tier_symbol), start, limit)
+    }
+
+    // Time-based curves and sale phases move the price between writes, so
+    // `current_price` is recomputed for every read
+    pub fn get_tier(e: &Env, tier_symbol: Symbol) -> Result<Tier, TicketError> {
+        let mut tier = read_tier(e, &tier_symbol)?;
+        tier.current_price = ticket_price(e, &tier, tier.base_price);
+        Ok(tier)
+    }
+
+    // Accept a payment token for a tier at the given base price
+    pub fn set_accepted_token(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        payment_token: Address,
+        base_price: i128,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        read_tier(e, &tier_symbol)?;
+        if base_price < 0 {
+            return Err(TicketError::InvalidPrice);
+        }
+
+        set_persistent(
+            e,
+            &DataKey::TierPrice(tier_symbol.clone(), payment_token.clone()),
+            &base_price,
+        );
+
+        TierPriceSet {
+            tier_symbol,
+            payment_token,
+            base_price,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    pub fn remove_accepted_token(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        payment_token: Address,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let key = DataKey::TierPrice(tier_symbol.clone(), payment_token.clone());
+        if !e.storage().persistent().has(&key) {
+            return Err(TicketError::PaymentTokenNotAccepted);
+        }
+        e.storage().persistent().remove(&key);
+
+        TierPriceRemoved {
+            tier_symbol,
+            payment_token,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Tickets `buyer` can still purchase from a tier
+    pub fn remaining_allowance(
+        e: &Env,
+        buyer: Address,
+        tier_symbol: Symbol,
+    ) -> Result<u32, TicketError> {
+        let tier = read_tier(e, &tier_symbol)?;
+        wallet_allowance(e, &buyer, &tier_symbol, &tier)
+    }
+
+    // Dynamic pricing query, in the tier's reference base price
+    pub fn get_ticket_price(e: &Env, tier_symbol: Symbol) -> Result<i128, TicketError> {
+        let tier = read_tier(e, &tier_symbol)?;
+        Ok(ticket_price(e, &tier, tier.base_price))
+    }
+
+    // Dynamic pricing query, in an accepted payment token
+    pub fn quote_price(
+        e: &Env,
+        tier_symbol: Symbol,
+        payment_token: Address,
+    ) -> Result<i128, TicketError> {
+        let tier = read_tier(e, &tier_symbol)?;
+        let base_price = read_tier_price(e, &tier_symbol, &payment_token)?;
+        Ok(ticket_price(e, &tier, base_price))
+    }
+
+    // Batch Minting for Organizer
+    pub fn batch_mint(
+        e: &Env,
+        caller: Address,
+        to: Address,
+        tier_symbol: Symbol,
+        amount: u32,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &MINTER)?;
+        ensure_not_cancelled(e)?;
 
+        let mut tier = read_tier(e, &tier_symbol)?;
         if tier.minted + amount > tier.max_supply {
-            panic!("Exceeds tier max supply");
+            return Err(TicketError::ExceedsMaxSupply);
         }
 
         for _ in 0..amount {
-            // custom sequential increment
-            let mut counter: u32 = e
-                .storage()
-                .instance()
-                .get(&DataKey::TokenIdCounter)
-                .unwrap();
-            counter += 1;
-            let token_id = counter;
-            e.storage()
-                .instance()
-                .set(&DataKey::TokenIdCounter, &counter);
-
-            Base::sequential_mint(e, &to);
-
-            let ticket = Ticket {
+            // Admin mints are free
+            let token_id = mint_ticket(e, &to, &tier_symbol, 0, None, None);
+            TicketMinted {
                 tier_symbol: tier_symbol.clone(),
-                purchase_time: e.ledger().timestamp(),
-                price_paid: 0, // Admin mints are free
-                is_valid: true,
-            };
-            e.storage()
-                .persistent()
-                .set(&DataKey::Ticket(token_id), &ticket);
+                to: to.clone(),
+                token_id,
+            }
+            .publish(e);
         }
 
         tier.minted += amount;
-        e.storage().persistent().set(&key, &tier);
+        write_tier(e, &tier_symbol, &mut tier);
+        Ok(())
     }
 
     // Purchase a ticket
-    pub fn purchase(e: &Env, buyer: Address, payment_token: Address, tier_symbol: Symbol) {
+    pub fn purchase(
+        e: &Env,
+        buyer: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+    ) -> Result<u32, TicketError> {
         buyer.require_auth();
 
-        let key = DataKey::Tier(tier_symbol.clone());
-        let mut tier: Tier = e
-            .storage()
-            .persistent()
-            .get(&key)
-            .unwrap_or_else(|| panic!("Tier not found"));
+        ensure_open_sale(e, &tier_symbol)?;
+        sell_ticket(e, &buyer, &payment_token, &tier_symbol, None)
+    }
+
+    // Purchase several tickets of one tier for a single payment
+    pub fn purchase_many(
+        e: &Env,
+        buyer: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+        quantity: u32,
+    ) -> Result<Vec<u32>, TicketError> {
+        buyer.require_auth();
+
+        ensure_open_sale(e, &tier_symbol)?;
+        sell_tickets(
+            e,
+            &buyer,
+            &buyer,
+            &payment_token,
+            &tier_symbol,
+            quantity,
+            None,
+        )
+    }
+
+    // Buy a ticket as a gift. The payer funds it and receives any refund, while
+    // the recipient holds the ticket and has it counted against their wallet caps.
+    pub fn purchase_for(
+        e: &Env,
+        payer: Address,
+        recipient: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+    ) -> Result<u32, TicketError> {
+        payer.require_auth();
+
+        ensure_open_sale(e, &tier_symbol)?;
+        let token_ids = sell_tickets(e, &payer, &recipient, &payment_token, &tier_symbol, 1, None)?;
+        Ok(token_ids.get_unchecked(0))
+    }
 
-        if !tier.active {
-            panic!("Tier is not active");
+    // Register seats for a tier. Once a tier has seats it only sells through
+    // `purchase_seat`, so allowlisted tiers cannot be seated.
+    pub fn add_seats(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        seats: Vec<Seat>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        read_tier(e, &tier_symbol)?;
+        if read_allowlist_root(e, &tier_symbol).is_some() {
+            return Err(TicketError::SeatedAllowlist);
         }
-        if tier.minted >= tier.max_supply {
-            panic!("Tier sold out");
+
+        let mut sections = read_sections(e, &tier_symbol);
+        for seat in seats.iter() {
+            let key = DataKey::SectionSeats(tier_symbol.clone(), seat.section.clone());
+            let mut section_seats: Vec<Seat> = get_persistent(e, &key).unwrap_or(Vec::new(e));
+            if section_seats.contains(&seat) {
+                return Err(TicketError::SeatAlreadyExists);
+            }
+            if !sections.contains(&seat.section) {
+                sections.push_back(seat.section.clone());
+            }
+            section_seats.push_back(seat);
+            set_persistent(e, &key, &section_seats);
         }
+        set_persistent(e, &DataKey::Sections(tier_symbol.clone()), &sections);
 
-        let price = Self::get_ticket_price(e, tier_symbol.clone());
+        SeatsAdded { tier_symbol, seats }.publish(e);
+        Ok(())
+    }
 
-        // Process payment
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        let token_client = token::Client::new(e, &payment_token);
-        token_client.transfer(&buyer, &admin, &price);
+    // Buy a specific free seat in a seated tier
+    pub fn purchase_seat(
+        e: &Env,
+        buyer: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+        seat: Seat,
+    ) -> Result<u32, TicketError> {
+        buyer.require_auth();
+        if read_allowlist_root(e, &tier_symbol).is_some() {
+            return Err(TicketError::AllowlistRequired);
+        }
 
-        // Mint Token
-        let mut counter: u32 = e
-            .storage()
-            .instance()
-            .get(&DataKey::TokenIdCounter)
-            .unwrap();
-        counter += 1;
-        let token_id = counter;
-        e.storage()
-            .instance()
-            .set(&DataKey::TokenIdCounter, &counter);
+        let section_seats = read_section_seats(e, &tier_symbol, &seat.section);
+        if !section_seats.contains(&seat) {
+            return Err(TicketError::SeatNotFound);
+        }
+        let holder_key = DataKey::SeatHolder(tier_symbol.clone(), seat.clone());
+        if e.storage().persistent().has(&holder_key) {
+            return Err(TicketError::SeatTaken);
+        }
 
-        Base::sequential_mint(e, &buyer);
+        let token_id = sell_ticket(e, &buyer, &payment_token, &tier_symbol, None)?;
+        set_persistent(e, &holder_key, &token_id);
+        let mut ticket = read_ticket(e, token_id)?;
+        ticket.seat = Some(seat.clone());
+        write_ticket(e, token_id, &ticket);
 
-        let ticket = Ticket {
-            tier_symbol: tier_symbol.clone(),
-            purchase_time: e.ledger().timestamp(),
-            price_paid: price,
-            is_valid: true,
-        };
-        e.storage()
-            .persistent()
-            .set(&DataKey::Ticket(token_id), &ticket);
+        SeatAssigned {
+            tier_symbol,
+            owner: buyer,
+            token_id,
+            seat,
+        }
+        .publish(e);
+        Ok(token_id)
+    }
 
-        tier.minted += 1;
-        e.storage().persistent().set(&key, &tier);
+    pub fn get_sections(e: &Env, tier_symbol: Symbol) -> Vec<Symbol> {
+        read_sections(e, &tier_symbol)
     }
 
-    // Refund a ticket
-    pub fn refund(e: &Env, owner: Address, payment_token: Address, token_id: u32) {
-        owner.require_auth();
+    // Seats among the section's seats `start..start + limit` that have not been
+    // sold, so a page can hold fewer than `limit` seats
+    pub fn available_seats(
+        e: &Env,
+        tier_symbol: Symbol,
+        section: Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Vec<Seat> {
+        let mut available = Vec::new(e);
+        let seats = read_section_seats(e, &tier_symbol, &section);
+        for seat in page(&seats, start, limit).iter() {
+            let holder_key = DataKey::SeatHolder(tier_symbol.clone(), seat.clone());
+            if !e.storage().persistent().has(&holder_key) {
+                available.push_back(seat);
+            }
+        }
+        available
+    }
 
-        let current_owner = Self::owner_of(e, token_id);
-        if owner != current_owner {
-            panic!("Not the ticket owner");
+    pub fn seat_holder(e: &Env, tier_symbol: Symbol, seat: Seat) -> Option<u32> {
+        get_persistent(e, &DataKey::SeatHolder(tier_symbol, seat))
+    }
+
+    // Restrict a tier to buyers in a Merkle allowlist, or open it again with `None`.
+    // See `allowlist::leaf` for how leaves are built. Seated tiers cannot be
+    // allowlisted.
+    pub fn set_allowlist_root(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        root: Option<BytesN<32>>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        read_tier(e, &tier_symbol)?;
+        if root.is_some() && is_seated(e, &tier_symbol) {
+            return Err(TicketError::SeatedAllowlist);
         }
 
-        let event_info: EventInfo = e.storage().instance().get(&DataKey::EventInfo).unwrap();
-        if e.ledger().timestamp() > event_info.refund_cutoff_time {
-            panic!("Refund window closed");
+        let key = DataKey::AllowlistRoot(tier_symbol.clone());
+        match &root {
+            Some(root) => set_persistent(e, &key, root),
+            None => e.storage().persistent().remove(&key),
         }
 
-        let mut ticket: Ticket = e
-            .storage()
-            .persistent()
-            .get(&DataKey::Ticket(token_id))
-            .unwrap();
-        if !ticket.is_valid {
-            panic!("Ticket already invalidated");
+        AllowlistRootSet { tier_symbol, root }.publish(e);
+        Ok(())
+    }
+
+    // Purchase from an allowlisted tier by proving `(buyer, allocation)` is a leaf
+    // of the tier's root. Tickets bought against an allocation stay consumed even
+    // if refunded, so a proof cannot be replayed.
+    pub fn purchase_allowlisted(
+        e: &Env,
+        buyer: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+        allocation: u32,
+        proof: Vec<BytesN<32>>,
+    ) -> Result<u32, TicketError> {
+        buyer.require_auth();
+
+        if is_seated(e, &tier_symbol) {
+            return Err(TicketError::SeatSelectionRequired);
+        }
+        let root = read_allowlist_root(e, &tier_symbol).ok_or(TicketError::AllowlistNotSet)?;
+        let leaf = allowlist::leaf(e, &buyer, allocation);
+        if !allowlist::verify(e, &root, leaf, &proof) {
+            return Err(TicketError::InvalidProof);
         }
 
-        // Process refund
-        let admin: Address = e.storage().instance().get(&DataKey::Admin).unwrap();
-        let token_client = token::Client::new(e, &payment_token);
-        token_client.transfer(&admin, &owner, &ticket.price_paid);
+        let key = DataKey::AllowlistClaimed(tier_symbol.clone(), buyer.clone());
+        let claimed: u32 = get_persistent(e, &key).unwrap_or(0);
+        if allocation > 0 && claimed >= allocation {
+            return Err(TicketError::AllocationExhausted);
+        }
 
-        // Invalidate and Burn
-        ticket.is_valid = false;
-        e.storage()
-            .persistent()
-            .set(&DataKey::Ticket(token_id), &ticket);
-        Base::burn(e, &owner, token_id);
+        let token_id = sell_ticket(e, &buyer, &payment_token, &tier_symbol, None)?;
+        set_persistent(e, &key, &(claimed + 1));
+        Ok(token_id)
     }
 
-    // Ticket Validation
-    pub fn validate_ticket(e: &Env, token_id: u32) -> bool {
-        let key = DataKey::Ticket(token_id);
-        if !e.storage().persistent().has(&key) {
-            return false;
+    // Register the ed25519 key our backend signs purchase vouchers with, or
+    // disable vouchers with `None`.
+    pub fn set_voucher_signer(
+        e: &Env,
+        caller: Address,
+        public_key: Option<BytesN<32>>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        match &public_key {
+            Some(key) => e.storage().instance().set(&DataKey::VoucherSigner, key),
+            None => e.storage().instance().remove(&DataKey::VoucherSigner),
         }
-        let ticket: Ticket = e.storage().persistent().get(&key).unwrap();
-        ticket.is_valid
-    }
 
-    // View functions logic
-    pub fn get_ticket(e: &Env, token_id: u32) -> Ticket {
-        e.storage()
-            .persistent()
-            .get(&DataKey::Ticket(token_id))
-            .unwrap()
+        VoucherSignerSet { public_key }.publish(e);
+        Ok(())
     }
-}
 
-// Implement SEP-0054 via OpenZeppelin Interface
-#[contractimpl]
-impl NonFungibleToken for SoulboundTicketContract {
-    type ContractType = Base;
+    // Buy a ticket at the price in a voucher signed off-chain by the voucher
+    // signer. The signature covers `(contract address, voucher)` as XDR; an
+    // invalid signature traps in the host. Each nonce can be redeemed once.
+    pub fn purchase_with_voucher(
+        e: &Env,
+        voucher: Voucher,
+        signature: BytesN<64>,
+    ) -> Result<u32, TicketError> {
+        voucher.buyer.require_auth();
+        if is_seated(e, &voucher.tier_symbol) {
+            return Err(TicketError::SeatSelectionRequired);
+        }
 
-    fn balance(e: &Env, owner: Address) -> u32 {
-        Self::ContractType::balance(e, &owner)
-    }
+        let signer: BytesN<32> = e
+            .storage()
+            .instance()
+            .get(&DataKey::VoucherSigner)
+            .ok_or(TicketError::VoucherSignerNotSet)?;
+        if e.ledger().timestamp() > voucher.expires_at {
+            return Err(TicketError::VoucherExpired);
+        }
+        let nonce_key = DataKey::VoucherNonce(voucher.nonce);
+        if e.storage().persistent().has(&nonce_key) {
+            return Err(TicketError::VoucherAlreadyUsed);
+        }
 
-    fn owner_of(e: &Env, token_id: u32) -> Address {
-        Self::ContractType::owner_of(e, token_id)
+        let payload = (e.current_contract_address(), voucher.clone()).to_xdr(e);
+        e.crypto().ed25519_verify(&signer, &payload, &signature);
+        set_persistent(e, &nonce_key, &true);
+
+        sell_ticket(
+            e,
+            &voucher.buyer,
+            &voucher.payment_token,
+            &voucher.tier_symbol,
+            Some(voucher.price),
+        )
     }
 
-    // Soulbound restrictions overrides
-    fn transfer(_e: &Env, _from: Address, _to: Address, _token_id: u32) {
-        panic!("Soulbound: Tickets cannot be transferred");
+    pub fn is_voucher_used(e: &Env, nonce: u64) -> bool {
+        e.storage().persistent().has(&DataKey::VoucherNonce(nonce))
     }
 
-    fn transfer_from(_e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
-        panic!("Soulbound: Tickets cannot be transferred");
+    // Register or replace a promo code by the sha256 of its plaintext, so the
+    // code itself only appears on-chain once someone redeems it. `uses` is
+    // owned by the contract: a replaced code keeps its redemption count.
+    pub fn set_promo_code(
+        e: &Env,
+        caller: Address,
+        code_hash: BytesN<32>,
+        mut promo: PromoCode,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        match promo.discount {
+            Discount::Percent(bps) if bps > 10000 => return Err(TicketError::InvalidPromoCode),
+            Discount::Fixed(amount) if amount < 0 => return Err(TicketError::InvalidPromoCode),
+            _ => {}
+        }
+
+        let key = DataKey::PromoCode(code_hash.clone());
+        promo.uses = get_persistent::<PromoCode>(e, &key).map_or(0, |stored| stored.uses);
+        set_persistent(e, &key, &promo);
+        PromoCodeSet { code_hash, promo }.publish(e);
+        Ok(())
     }
 
-    fn approve(
-        _e: &Env,
-        _approver: Address,
-        _approved: Address,
-        _token_id: u32,
-        _live_until_ledger: u32,
-    ) {
-        panic!("Soulbound: Approval disabled for non-transferable tokens");
+    pub fn remove_promo_code(
+        e: &Env,
+        caller: Address,
+        code_hash: BytesN<32>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        read_promo_code(e, &code_hash)?;
+
+        e.storage()
+            .persistent()
+            .remove(&DataKey::PromoCode(code_hash.clone()));
+        PromoCodeRemoved { code_hash }.publish(e);
+        Ok(())
     }
 
-    fn approve_for_all(_e: &Env, _owner: Address, _operator: Address, _live_until_ledger: u32) {
-        panic!("Soulbound: Approval disabled for non-transferable tokens");
+    pub fn get_promo_code(e: &Env, code_hash: BytesN<32>) -> Result<PromoCode, TicketError> {
+        read_promo_code(e, &code_hash)
     }
 
-    fn get_approved(_e: &Env, _token_id: u32) -> Option<Address> {
-        None
+    // Purchase a ticket with a promo code discount applied on top of the
+    // current ticket price
+    pub fn purchase_with_code(
+        e: &Env,
+        buyer: Address,
+        payment_token: Address,
+        tier_symbol: Symbol,
+        code: String,
+    ) -> Result<u32, TicketError> {
+        buyer.require_auth();
+
+        ensure_open_sale(e, &tier_symbol)?;
+
+        let code_hash: BytesN<32> = e.crypto().sha256(&code.to_bytes()).to_bytes();
+        let mut promo = read_promo_code(e, &code_hash)?;
+        if promo.expires_at != 0 && e.ledger().timestamp() > promo.expires_at {
+            return Err(TicketError::PromoCodeExpired);
+        }
+        if !promo.tiers.is_empty() && !promo.tiers.contains(&tier_symbol) {
+            return Err(TicketError::PromoCodeNotApplicable);
+        }
+        if promo.max_uses != 0 && promo.uses >= promo.max_uses {
+            return Err(TicketError::PromoCodeExhausted);
+        }
+        let uses_key = DataKey::PromoUses(code_hash.clone(), buyer.clone());
+        let wallet_uses: u32 = get_persistent(e, &uses_key).unwrap_or(0);
+        if promo.max_per_wallet != 0 && wallet_uses >= promo.max_per_wallet {
+            return Err(TicketError::PromoCodeExhausted);
+        }
+
+        let tier = read_tier(e, &tier_symbol)?;
+        let base_price = read_tier_price(e, &tier_symbol, &payment_token)?;
+        let price = ticket_price(e, &tier, base_price);
+        let discount = match promo.discount {
+            Discount::Percent(bps) => price * bps as i128 / 10000,
+            Discount::Fixed(amount) => amount.min(price),
+        };
+
+        let token_id = sell_ticket(
+            e,
+            &buyer,
+            &payment_token,
+            &tier_symbol,
+            Some(price - discount),
+        )?;
+
+        promo.uses += 1;
+        set_persistent(e, &DataKey::PromoCode(code_hash.clone()), &promo);
+        set_persistent(e, &uses_key, &(wallet_uses + 1));
+
+        let mut ticket = read_ticket(e, token_id)?;
+        ticket.promo_code = Some(code_hash.clone());
+        write_ticket(e, token_id, &ticket);
+
+        PromoCodeRedeemed {
+            tier_symbol,
+            buyer,
+            code_hash,
+            token_id,
+            discount,
+        }
+        .publish(e);
+        Ok(token_id)
     }
 
-    fn is_approved_for_all(_e: &Env, _owner: Address, _operator: Address) -> bool {
-        false
+    // Allowlist tickets `buyer` has bought from a tier
+    pub fn allowlist_claimed(e: &Env, tier_symbol: Symbol, buyer: Address) -> u32 {
+        get_persistent(e, &DataKey::AllowlistClaimed(tier_symbol, buyer)).unwrap_or(0)
     }
 
-    // Metadata
-    fn name(e: &Env) -> String {
-        Self::ContractType::name(e)
+    // Refund a ticket in the token and amount it was bought with
+    pub fn refund(e: &Env, owner: Address, token_id: u32) -> Result<(), TicketError> {
+        owner.require_auth();
+
+        let mut ticket = read_ticket(e, token_id)?;
+        // Refunded tickets are burned and no longer have an owner
+        if !ticket.is_valid {
+            return Err(TicketError::TicketInvalidated);
+        }
+        let current_owner = Self::owner_of(e, token_id);
+        if owner != current_owner {
+            return Err(TicketError::NotTicketOwner);
+        }
+
+        let event_info = read_event_info(e)?;
+        let amount =
+            refund_amount(e, &event_info, &ticket).ok_or(TicketError::RefundWindowClosed)?;
+
+        refund_ticket(e, &owner, token_id, &mut ticket, amount)
     }
 
-    fn symbol(e: &Env) -> String {
-        Self::ContractType::symbol(e)
+    // Amount `refund` would pay out for this ticket right now
+    pub fn quote_refund(e: &Env, token_id: u32) -> Result<i128, TicketError> {
+        let ticket = read_ticket(e, token_id)?;
+        let event_info = read_event_info(e)?;
+        if !ticket.is_valid || ticket.checked_in_at.is_some() {
+            return Ok(0);
+        }
+        Ok(refund_amount(e, &event_info, &ticket).unwrap_or(0))
     }
 
-    fn token_uri(e: &Env, token_id: u32) -> String {
-        Self::ContractType::token_uri(e, token_id)
+    // Same as `quote_refund`
+    pub fn claimable_refund(e: &Env, token_id: u32) -> Result<i128, TicketError> {
+        Self::quote_refund(e, token_id)
     }
-}
 
-// Ownable Utils
-#[contractimpl]
-impl Ownable for SoulboundTicketContract {
-    fn get_owner(e: &Env) -> Option<Address> {
-        ownable::get_owner(e)
+    // Replace the refund schedule and cancellation fee. Each step refunds
+    // `refund_bps` of the price paid until its `until` time; steps must be in
+    // time order and refunds close after the last one. An empty schedule falls
+    // back to a full refund until `refund_cutoff_time`. The fee is withheld
+    // from every holder-requested refund and stays in escrow for settlement.
+    pub fn set_refund_policy(
+        e: &Env,
+        caller: Address,
+        schedule: Vec<RefundStep>,
+        cancellation_fee: Option<CancellationFee>,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut previous_until = 0;
+        for step in schedule.iter() {
+            if step.until <= previous_until || step.refund_bps > 10000 {
+                return Err(TicketError::InvalidRefundPolicy);
+            }
+            previous_until = step.until;
+        }
+        match &cancellation_fee {
+            Some(CancellationFee::Fixed(amount)) if *amount < 0 => {
+                return Err(TicketError::InvalidRefundPolicy)
+            }
+            Some(CancellationFee::Bps(bps)) if *bps > 10000 => {
+                return Err(TicketError::InvalidRefundPolicy)
+            }
+            _ => {}
+        }
+
+        let mut event_info = read_event_info(e)?;
+        event_info.refund_schedule = schedule.clone();
+        event_info.cancellation_fee = cancellation_fee.clone();
+        e.storage().instance().set(&DataKey::EventInfo, &event_info);
+
+        RefundPolicySet {
+            schedule,
+            cancellation_fee,
+        }
+        .publish(e);
+        Ok(())
     }
 
-    fn transfer_ownership(e: &Env, new_owner: Address, live_until_ledger: u32) {
-        ownable::transfer_ownership(e, &new_owner, live_until_ledger);
+    // Cancel the event: sales and check-in stop and every holder can claim a full refund.
+    // Only possible until the event ends, so escrow can never have been settled.
+    pub fn cancel_event(e: &Env, caller: Address) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut event_info = read_event_info(e)?;
+        if event_info.cancelled {
+            return Err(TicketError::EventCancelled);
+        }
+        if e.ledger().timestamp() > event_info.end_time {
+            return Err(TicketError::EventEnded);
+        }
+        event_info.cancelled = true;
+        e.storage().instance().set(&DataKey::EventInfo, &event_info);
+
+        EventCancelled {
+            caller,
+            cancelled_at: e.ledger().timestamp(),
+        }
+        .publish(e);
+        Ok(())
     }
 
-    fn accept_ownership(e: &Env) {
-        ownable::accept_ownership(e);
+    pub fn is_cancelled(e: &Env) -> Result<bool, TicketError> {
+        Ok(read_event_info(e)?.cancelled)
     }
 
-    fn renounce_ownership(e: &Env) {
-        ownable::renounce_ownership(e);
+    // Finance-issued refund, not bound by the refund window
+    pub fn issue_refund(e: &Env, caller: Address, token_id: u32) -> Result<(), TicketError> {
+        require_role(e, &caller, &FINANCE)?;
+
+        let mut ticket = read_ticket(e, token_id)?;
+        if !ticket.is_valid {
+            return Err(TicketError::TicketInvalidated);
+        }
+        let owner = Self::owner_of(e, token_id);
+        let amount = ticket.price_paid;
+        refund_ticket(e, &owner, token_id, &mut ticket, amount)
     }
+
+    // Move a ticket to another tier in the token it was bought with, paying the
+    // difference between the target tier's current price and what was paid so
+    // far. Moving to a cheaper tier costs nothing and refunds nothing. Gifted
+    // tickets can only be moved when there is nothing to pay.
+    pub fn upgrade_ticket(
+        e: &Env,
+        owner: Address,
+        token_id: u32,
+        new_tier: Symbol,
+    ) -> Result<(), TicketError> {
+        owner.require_auth();
+        ensure_not_cancelled(e)?;
+
+        let mut ticket = read_ticket(e, token_id)?;
+        if !ticket.is_valid {
+            return Err(TicketError::TicketInvalidated);
+        }
+        if Self::owner_of(e, token_id) != owner {
+            return Err(TicketError::NotTicketOwner);
+        }
+        if ticket.checked_in_at.is_some() {
+            return Err(TicketError::AlreadyCheckedIn);
+        }
+        let payment_token = ticket
+            .payment_token
+            .clone()
+            .ok_or(TicketError::InvalidUpgrade)?;
+        let old_tier = ticket.tier_symbol.clone();
+        if old_tier == new_tier {
+            return Err(TicketError::InvalidUpgrade);
+        }
+
+        ensure_open_sale(e, &new_tier)?;
+        let mut target = read_tier(e, &new_tier)?;
+        let phase = ensure_on_sale(e, &target)?;
+        let tier_key = DataKey::TierPurchases(new_tier.clone(), owner.clone());
+        if target.max_per_wallet > 0 && read_wallet_count(e, &tier_key) >= target.max_per_wallet {
+            return Err(TicketError::WalletLimitReached);
+        }
+
+        let base_price = read_tier_price(e, &new_tier, &payment_token)?;
+        let amount_paid = (ticket_price(e, &target, base_price) - ticket.price_paid).max(0);
+        // Refunds go to the payer, who must not collect what the holder pays
+        if amount_paid > 0 && ticket.payer.as_ref().is_some_and(|payer| *payer != owner) {
+            return Err(TicketError::GiftedTicket);
+        }
+        if amount_paid > 0 {
+            let token_client = token::Client::new(e, &payment_token);
+            token_client.transfer(&owner, &e.current_contract_address(), &amount_paid);
+            write_escrow(
+                e,
+                &payment_token,
+                read_escrow(e, &payment_token) + amount_paid,
+            );
+        }
+
+        let mut source = read_tier(e, &old_tier)?;
+        source.minted -= 1;
+        write_tier(e, &old_tier, &mut source);
+        target.minted += 1;
+        record_phase_sale(&mut target, phase);
+        write_tier(e, &new_tier, &mut target);
+
+        release_wallet_purchase(e, &owner, &old_tier);
+        record_wallet_purchase(e, &owner, &new_tier);
+
+        // A listing was priced against the old tier
+        e.storage().persistent().remove(&DataKey::Listing(token_id));
+
+        ticket.upgrades.push_back(TicketUpgrade {
+            from_tier: old_tier.clone(),
+            to_tier: new_tier.clone(),
+            amount_paid,
+            upgraded_at: e.ledger().timestamp(),
+        });
+        // Seats belong to the tier being left
+        release_seat(e, &old_tier, &ticket);
+        ticket.seat = None;
+        index_remove(e, &TicketIndex::Tier(old_tier.clone()), token_id);
+        index_add(e, &TicketIndex::Tier(new_tier.clone()), token_id);
+        ticket.tier_symbol = new_tier.clone();
+        ticket.price_paid += amount_paid;
+        write_ticket(e, token_id, &ticket);
+
+        TicketUpgraded {
+            tier_symbol: new_tier,
+            owner,
+            token_id,
+            from_tier: old_tier,
+            amount_paid,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Configure resale. Listings are capped at `max_price_bps` of what the ticket
+    // was originally bought for and the organizer keeps `royalty_bps` of each sale.
+    pub fn set_resale_policy(
+        e: &Env,
+        caller: Address,
+        policy: ResalePolicy,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+        if policy.royalty_bps > 10000 {
+            return Err(TicketError::InvalidResalePolicy);
+        }
+
+        e.storage().instance().set(&DataKey::ResalePolicy, &policy);
+        ResalePolicySet {
+            enabled: policy.enabled,
+            max_price_bps: policy.max_price_bps,
+            royalty_bps: policy.royalty_bps,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    pub fn get_resale_policy(e: &Env) -> ResalePolicy {
+        read_resale_policy(e)
+    }
+
+    // List a ticket for resale in the token it was bought with. Plain transfers stay disabled.
+    pub fn list_for_resale(
+        e: &Env,
+        seller: Address,
+        token_id: u32,
+        price: i128,
+    ) -> Result<(), TicketError> {
+        seller.require_auth();
+
+        let policy = read_resale_policy(e);
+        if !policy.enabled {
+            return Err(TicketError::ResaleDisabled);
+        }
+        ensure_not_cancelled(e)?;
+
+        let ticket = read_ticket(e, token_id)?;
+        if !ticket.is_valid {
+            return Err(TicketError::TicketInvalidated);
+        }
+        if Self::owner_of(e, token_id) != seller {
+            return Err(TicketError::NotTicketOwner);
+        }
+        if ticket.checked_in_at.is_some() {
+            return Err(TicketError::AlreadyCheckedIn);
+        }
+        // Comp tickets were never paid for and cannot be sold on
+        if ticket.payment_token.is_none() {
+            return Err(TicketError::TicketNotResellable);
+        }
+        if price < 0 || price > ticket.price_paid * policy.max_price_bps as i128 / 10000 {
+            return Err(TicketError::ResalePriceTooHigh);
+        }
+
+        let listing = Listing {
+            seller: seller.clone(),
+            price,
+        };
+        set_persistent(e, &DataKey::Listing(token_id), &listing);
+
+        TicketListed {
+            tier_symbol: ticket.tier_symbol,
+            seller,
+            token_id,
+            price,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    pub fn cancel_listing(e: &Env, seller: Address, token_id: u32) -> Result<(), TicketError> {
+        seller.require_auth();
+
+        let listing = read_listing(e, token_id)?;
+        if listing.seller != seller {
+            return Err(TicketError::NotTicketOwner);
+        }
+        e.storage().persistent().remove(&DataKey::Listing(token_id));
+
+        let ticket = read_ticket(e, token_id)?;
+        ListingCancelled {
+            tier_symbol: ticket.tier_symbol,
+            seller,
+            token_id,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    pub fn get_listing(e: &Env, token_id: u32) -> Result<Listing, TicketError> {
+        read_listing(e, token_id)
+    }
+
+    // Buy a listed ticket. The royalty stays in escrow with the rest of the
+    // organizer's revenue, as does the original price so the ticket remains
+    // refundable; the new holder becomes the ticket's payer for refunds.
+    pub fn buy_resale(e: &Env, buyer: Address, token_id: u32) -> Result<(), TicketError> {
+        buyer.require_auth();
+
+        let policy = read_resale_policy(e);
+        if !policy.enabled {
+            return Err(TicketError::ResaleDisabled);
+        }
+        ensure_not_cancelled(e)?;
+
+        let listing = read_listing(e, token_id)?;
+        let mut ticket = read_ticket(e, token_id)?;
+        let seller = listing.seller;
+        if !ticket.is_valid || Self::owner_of(e, token_id) != seller {
+            return Err(TicketError::ListingNotFound);
+        }
+        if ticket.checked_in_at.is_some() {
+            return Err(TicketError::AlreadyCheckedIn);
+        }
+
+        let tier = read_tier(e, &ticket.tier_symbol)?;
+        if wallet_cap_allowance(e, &buyer, &ticket.tier_symbol, &tier)? == 0 {
+            return Err(TicketError::WalletLimitReached);
+        }
+
+        let payment_token = ticket
+            .payment_token
+            .clone()
+            .ok_or(TicketError::TicketNotResellable)?;
+        let royalty = listing.price * policy.royalty_bps as i128 / 10000;
+        let token_client = token::Client::new(e, &payment_token);
+        token_client.transfer(&buyer, &e.current_contract_address(), &listing.price);
+        token_client.transfer(
+            &e.current_contract_address(),
+            &seller,
+            &(listing.price - royalty),
+        );
+        write_escrow(e, &payment_token, read_escrow(e, &payment_token) + royalty);
+
+        e.storage().persistent().remove(&DataKey::Listing(token_id));
+        Base::update(e, Some(&seller), Some(&buyer), token_id);
+        extend_ownership(e, &buyer, token_id);
+        index_remove(e, &TicketIndex::Owner(seller.clone()), token_id);
+        index_add(e, &TicketIndex::Owner(buyer.clone()), token_id);
+        release_wallet_purchase(e, &seller, &ticket.tier_symbol);
+        record_wallet_purchase(e, &buyer, &ticket.tier_symbol);
+        ticket.payer = Some(buyer.clone());
+        write_ticket(e, token_id, &ticket);
+
+        TicketResold {
+            tier_symbol: ticket.tier_symbol,
+            seller,
+            buyer,
+            token_id,
+            price: listing.price,
+            royalty,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Where settled proceeds are paid out
+    pub fn set_payout_address(
+        e: &Env,
+        caller: Address,
+        payout: Address,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &FINANCE)?;
+        e.storage().instance().set(&DataKey::Payout, &payout);
+
+        PayoutAddressSet { caller, payout }.publish(e);
+        Ok(())
+    }
+
+    // Release escrowed proceeds once the event is over and the dispute window has passed
+    pub fn settle(e: &Env, caller: Address, payment_token: Address) -> Result<i128, TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        // Escrow of a cancelled event is reserved for refunds
+        let event_info = read_event_info(e)?;
+        if event_info.cancelled {
+            return Err(TicketError::EventCancelled);
+        }
+        if e.ledger().timestamp() <= event_info.end_time.saturating_add(DISPUTE_WINDOW) {
+            return Err(TicketError::SettlementLocked);
+        }
+
+        let amount = read_escrow(e, &payment_token);
+        if amount <= 0 {
+            return Err(TicketError::NothingToSettle);
+        }
+
+        let payout: Address = e
+            .storage()
+            .instance()
+            .get(&DataKey::Payout)
+            .ok_or(TicketError::NotInitialized)?;
+        write_escrow(e, &payment_token, 0);
+        let token_client = token::Client::new(e, &payment_token);
+        token_client.transfer(&e.current_contract_address(), &payout, &amount);
+
+        EscrowSettled {
+            payment_token,
+            payout,
+            amount,
+        }
+        .publish(e);
+        Ok(amount)
+    }
+
+    pub fn escrow_balance(e: &Env, payment_token: Address) -> i128 {
+        read_escrow(e, &payment_token)
+    }
+
+    // Whether a ticket may be scanned again after its first check-in
+    pub fn set_reentry_policy(
+        e: &Env,
+        caller: Address,
+        allow_reentry: bool,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut event_info = read_event_info(e)?;
+        event_info.allow_reentry = allow_reentry;
+        e.storage().instance().set(&DataKey::EventInfo, &event_info);
+
+        ReentryPolicySet { allow_reentry }.publish(e);
+        Ok(())
+    }
+
+    // Cap how many tickets one wallet may buy across the whole event. 0 removes the cap.
+    pub fn set_wallet_cap(
+        e: &Env,
+        caller: Address,
+        max_per_wallet: u32,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut event_info = read_event_info(e)?;
+        event_info.max_per_wallet = max_per_wallet;
+        e.storage().instance().set(&DataKey::EventInfo, &event_info);
+
+        WalletCapSet { max_per_wallet }.publish(e);
+        Ok(())
+    }
+
+    // Cap how many tickets of one tier a wallet may buy. 0 removes the cap.
+    pub fn set_tier_wallet_cap(
+        e: &Env,
+        caller: Address,
+        tier_symbol: Symbol,
+        max_per_wallet: u32,
+    ) -> Result<(), TicketError> {
+        require_role(e, &caller, &ORGANIZER)?;
+
+        let mut tier = read_tier(e, &tier_symbol)?;
+        tier.max_per_wallet = max_per_wallet;
+        write_tier(e, &tier_symbol, &mut tier);
+
+        TierWalletCapSet {
+            tier_symbol,
+            max_per_wallet,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    // Redeem a ticket at the door
+    pub fn check_in(e: &Env, scanner: Address, token_id: u32) -> Result<(), TicketError> {
+        scanner.require_auth();
+        if !holds_role(e, &scanner, &SCANNER) {
+            return Err(TicketError::UnauthorizedScanner);
+        }
+
+        let mut ticket = read_ticket(e, token_id)?;
+        if !ticket.is_valid {
+            return Err(TicketError::TicketInvalidated);
+        }
+
+        let event_info = read_event_info(e)?;
+        if event_info.cancelled {
+            return Err(TicketError::EventCancelled);
+        }
+        let now = e.ledger().timestamp();
+        let opens_at = event_info.start_time.saturating_sub(CHECK_IN_OPENS_BEFORE);
+        if now < opens_at || now > event_info.end_time {
+            return Err(TicketError::CheckInClosed);
+        }
+        if ticket.checked_in_at.is_some() && !event_info.allow_reentry {
+            return Err(TicketError::AlreadyCheckedIn);
+        }
+
+        ticket.checked_in_at = Some(now);
+        ticket.checked_in_by = Some(scanner.clone());
+        write_ticket(e, token_id, &ticket);
+
+        TicketCheckedIn {
+            tier_symbol: ticket.tier_symbol,
+            scanner,
+            token_id,
+            checked_in_at: now,
+        }
+        .publish(e);
+        Ok(())
+    }
+
+    pub fn is_checked_in(e: &Env, token_id: u32) -> bool {
+        read_ticket(e, token_id).is_ok_and(|ticket| ticket.checked_in_at.is_some())
+    }
+
+    // Ticket Validation
+    pub fn validate_ticket(e: &Env, token_id: u32) -> bool {
+        read_ticket(e, token_id).is_ok_and(|ticket| ticket.is_valid)
+    }
+
+    // View functions logic
+    pub fn get_ticket(e: &Env, token_id: u32) -> Result<Ticket, TicketError> {
+        read_ticket(e, token_id)
+    }
+
+    // Keep a ticket and everything it relies on from being archived before the
+    // event: its tier, price, seat, ownership and index entries. Anyone may pay for the
+    // bump; reading each entry extends its TTL.
+    pub fn bump_ticket(e: &Env, token_id: u32) -> Result<(), TicketError> {
+        let ticket = read_ticket(e, token_id)?;
+        read_tier(e, &ticket.tier_symbol)?;
+        // Burned tickets have no owner, index or seat entries left
+        if !ticket.is_valid {
+            return Ok(());
+        }
+
+        if let Some(payment_token) = &ticket.payment_token {
+            let _ = read_tier_price(e, &ticket.tier_symbol, payment_token);
+        }
+        if let Some(seat) = &ticket.seat {
+            get_persistent::<u32>(
+                e,
+                &DataKey::SeatHolder(ticket.tier_symbol.clone(), seat.clone()),
+            );
+        }
+        let owner = Self::owner_of(e, token_id);
+        extend_ownership(e, &owner, token_id);
+        index_extend(e, &TicketIndex::Owner(owner), token_id);
+        index_extend(e, &TicketIndex::Tier(ticket.tier_symbol), token_id);
+        Ok(())
+    }
+}
+
+// Implement SEP-0054 via OpenZeppelin Interface
+#[contractimpl]
+impl NonFungibleToken for SoulboundTicketContract {
+    type ContractType = Base;
+
+    fn balance(e: &Env, owner: Address) -> u32 {
+        Self::ContractType::balance(e, &owner)
+    }
+
+    fn owner_of(e: &Env, token_id: u32) -> Address {
+        Self::ContractType::owner_of(e, token_id)
+    }
+
+    // Soulbound restrictions overrides
+    fn transfer(e: &Env, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, TicketError::NonTransferable);
+    }
+
+    fn transfer_from(e: &Env, _spender: Address, _from: Address, _to: Address, _token_id: u32) {
+        panic_with_error!(e, TicketError::NonTransferable);
+    }
+
+    fn approve(
+        e: &Env,
+        _approver: Address,
+        _approved: Address,
+        _token_id: u32,
+        _live_until_ledger: u32,
+    ) {
+        panic_with_error!(e, TicketError::ApprovalDisabled);
+    }
+
+    fn approve_for_all(e: &Env, _owner: Address, _operator: Address, _live_until_ledger: u32) {
+        panic_with_error!(e, TicketError::ApprovalDisabled);
+    }
+
+    fn get_approved(_e: &Env, _token_id: u32) -> Option<Address> {
+        None
+    }
+
+    fn is_approved_for_all(_e: &Env, _owner: Address, _operator: Address) -> bool {
+        false
+    }
+
+    // Metadata
+    fn name(e: &Env) -> String {
+        Self::ContractType::name(e)
+    }
+
+    fn symbol(e: &Env) -> String {
+        Self::ContractType::symbol(e)
+    }
+
+    // Tiers with their own metadata URI use it in place of the collection base URI
+    fn token_uri(e: &Env, token_id: u32) -> String {
+        let ticket = read_ticket(e, token_id).unwrap_or_else(|err| panic_with_error!(e, err));
+        let prefix = read_tier(e, &ticket.tier_symbol)
+            .ok()
+            .and_then(|tier| tier.metadata_uri)
+            .unwrap_or_else(|| Base::base_uri(e));
+        metadata::ticket_uri(e, &prefix, token_id, &ticket)
+    }
+}
+
+// Role management
+#[contractimpl]
+impl AccessControl for SoulboundTicketContract {
+    fn has_role(e: &Env, account: Address, role: Symbol) -> Option<u32> {
+        access_control::has_role(e, &account, &role)
+    }
+
+    fn get_role_member_count(e: &Env, role: Symbol) -> u32 {
+        access_control::get_role_member_count(e, &role)
+    }
+
+    fn get_role_member(e: &Env, role: Symbol, index: u32) -> Address {
+        access_control::get_role_member(e, &role, index)
+    }
+
+    fn get_role_admin(e: &Env, role: Symbol) -> Option<Symbol> {
+        access_control::get_role_admin(e, &role)
+    }
+
+    fn get_admin(e: &Env) -> Option<Address> {
+        access_control::get_admin(e)
+    }
+
+    fn grant_role(e: &Env, account: Address, role: Symbol, caller: Address) {
+        access_control::grant_role(e, &account, &role, &caller);
+    }
+
+    fn revoke_role(e: &Env, account: Address, role: Symbol, caller: Address) {
+        access_control::revoke_role(e, &account, &role, &caller);
+    }
+
+    fn renounce_role(e: &Env, role: Symbol, caller: Address) {
+        access_control::renounce_role(e, &role, &caller);
+    }
+
+    fn transfer_admin_role(e: &Env, new_admin: Address, live_until_ledger: u32) {
+        access_control::transfer_admin_role(e, &new_admin, live_until_ledger);
+    }
+
+    fn accept_admin_transfer(e: &Env) {
+        access_control::accept_admin_transfer(e);
+    }
+
+    fn set_role_admin(e: &Env, role: Symbol, admin_role: Symbol) {
+        access_control::set_role_admin(e, &role, &admin_role);
+    }
+
+    fn renounce_admin(e: &Env) {
+        access_control::renounce_admin(e);
+    }
+}
+
+// Ownership is an alias for the access control admin so that a two-step
+// ownership transfer also moves every admin right with it.
+#[contractimpl]
+impl Ownable for SoulboundTicketContract {
+    fn get_owner(e: &Env) -> Option<Address> {
+        access_control::get_admin(e)
+    }
+
+    fn transfer_ownership(e: &Env, new_owner: Address, live_until_ledger: u32) {
+        access_control::transfer_admin_role(e, &new_owner, live_until_ledger);
+    }
+
+    fn accept_ownership(e: &Env) {
+        access_control::accept_admin_transfer(e);
+    }
+
+    fn renounce_ownership(e: &Env) {
+        access_control::renounce_admin(e);
+    }
+}
+
+fn holds_role(e: &Env, account: &Address, role: &Symbol) -> bool {
+    access_control::get_admin(e).as_ref() == Some(account)
+        || access_control::has_role(e, account, role).is_some()
+}
+
+fn require_role(e: &Env, caller: &Address, role: &Symbol) -> Result<(), TicketError> {
+    caller.require_auth();
+    if !holds_role(e, caller, role) {
+        return Err(TicketError::Unauthorized);
+    }
+    Ok(())
+}
+
+fn read_event_info(e: &Env) -> Result<EventInfo, TicketError> {
+    let event_info: EventInfo = e
+        .storage()
+        .instance()
+        .get(&DataKey::EventInfo)
+        .ok_or(TicketError::NotInitialized)?;
+    extend_instance(e, &event_info);
+    Ok(event_info)
+}
+
+// Ledgers an entry must stay live for: at least TTL_PERSISTENT, and long
+// enough to cover the event and its settlement window, capped by the network.
+fn required_ttl(e: &Env, event_info: &EventInfo) -> u32 {
+    let keep_until = event_info.end_time.saturating_add(DISPUTE_WINDOW);
+    let seconds_left = keep_until.saturating_sub(e.ledger().timestamp());
+    let ledgers_left = (seconds_left / LEDGER_SECONDS).min(u32::MAX as u64) as u32;
+    ledgers_left.max(TTL_PERSISTENT).min(e.storage().max_ttl())
+}
+
+fn extend_instance(e: &Env, event_info: &EventInfo) {
+    let ttl = required_ttl(e, event_info);
+    e.storage().instance().extend_ttl(ttl, ttl);
+}
+
+fn extend_persistent<K: IntoVal<Env, Val>>(e: &Env, key: &K) {
+    let ttl = match e
+        .storage()
+        .instance()
+        .get::<_, EventInfo>(&DataKey::EventInfo)
+    {
+        Some(event_info) => required_ttl(e, &event_info),
+        None => TTL_PERSISTENT,
+    };
+    e.storage().persistent().extend_ttl(key, ttl, ttl);
+}
+
+// Persistent entries are extended whenever they are written or found, so
+// anything the event touches lives at least until settlement
+fn get_persistent<V>(e: &Env, key: &DataKey) -> Option<V>
+where
+    V: TryFromVal<Env, Val>,
+    V::Error: core::fmt::Debug,
+{
+    let value = e.storage().persistent().get(key);
+    if value.is_some() {
+        extend_persistent(e, key);
+    }
+    value
+}
+
+fn set_persistent<V: IntoVal<Env, Val>>(e: &Env, key: &DataKey, value: &V) {
+    e.storage().persistent().set(key, value);
+    extend_persistent(e, key);
+}
+
+// The NFT base keeps its ownership entries on its own, shorter TTL
+fn extend_ownership(e: &Env, owner: &Address, token_id: u32) {
+    extend_persistent(e, &NFTStorageKey::Owner(token_id));
+    extend_persistent(e, &NFTStorageKey::Balance(owner.clone()));
+}
+
+fn ensure_not_cancelled(e: &Env) -> Result<(), TicketError> {
+    if read_event_info(e)?.cancelled {
+        return Err(TicketError::EventCancelled);
+    }
+    Ok(())
+}
+
+fn read_escrow(e: &Env, payment_token: &Address) -> i128 {
+    get_persistent(e, &DataKey::Escrow(payment_token.clone())).unwrap_or(0)
+}
+
+fn write_escrow(e: &Env, payment_token: &Address, amount: i128) {
+    let key = DataKey::Escrow(payment_token.clone());
+    e.storage().persistent().set(&key, &amount);
+    extend_persistent(e, &key);
+}
+
+fn read_tier(e: &Env, tier_symbol: &Symbol) -> Result<Tier, TicketError> {
+    let key = DataKey::Tier(tier_symbol.clone());
+    let tier = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(TicketError::TierNotFound)?;
+    extend_persistent(e, &key);
+    Ok(tier)
+}
+
+// Keeps `current_price` in step with `get_ticket_price` on every write
+fn write_tier(e: &Env, tier_symbol: &Symbol, tier: &mut Tier) {
+    tier.current_price = ticket_price(e, tier, tier.base_price);
+    let key = DataKey::Tier(tier_symbol.clone());
+    e.storage().persistent().set(&key, tier);
+    extend_persistent(e, &key);
+}
+
+fn read_tier_price(
+    e: &Env,
+    tier_symbol: &Symbol,
+    payment_token: &Address,
+) -> Result<i128, TicketError> {
+    get_persistent(
+        e,
+        &DataKey::TierPrice(tier_symbol.clone(), payment_token.clone()),
+    )
+    .ok_or(TicketError::PaymentTokenNotAccepted)
+}
+
+fn read_ticket(e: &Env, token_id: u32) -> Result<Ticket, TicketError> {
+    let key = DataKey::Ticket(token_id);
+    let ticket = e
+        .storage()
+        .persistent()
+        .get(&key)
+        .ok_or(TicketError::TicketNotFound)?;
+    extend_persistent(e, &key);
+    Ok(ticket)
+}
+
+fn write_ticket(e: &Env, token_id: u32, ticket: &Ticket) {
+    let key = DataKey::Ticket(token_id);
+    e.storage().persistent().set(&key, ticket);
+    extend_persistent(e, &key);
+}
+
+// Resale stays off until an organizer enables it
+fn read_resale_policy(e: &Env) -> ResalePolicy {
+    e.storage()
+        .instance()
+        .get(&DataKey::ResalePolicy)
+        .unwrap_or(ResalePolicy {
+            enabled: false,
+            max_price_bps: 10000,
+            royalty_bps: 0,
+        })
+}
+
+fn read_promo_code(e: &Env, code_hash: &BytesN<32>) -> Result<PromoCode, TicketError> {
+    get_persistent(e, &DataKey::PromoCode(code_hash.clone())).ok_or(TicketError::PromoCodeNotFound)
+}
+
+fn read_listing(e: &Env, token_id: u32) -> Result<Listing, TicketError> {
+    get_persistent(e, &DataKey::Listing(token_id)).ok_or(TicketError::ListingNotFound)
+}
+
+// Checks a tier can be bought from right now and returns the index of the
+// running sale phase, if the tier is phased.
+fn ensure_on_sale(e: &Env, tier: &Tier) -> Result<Option<u32>, TicketError> {
+    if tier.retired {
+        return Err(TicketError::TierRetired);
+    }
+    if !tier.active {
+        return Err(TicketError::TierNotActive);
+    }
+    if tier.minted >= tier.max_supply {
+        return Err(TicketError::TierSoldOut);
+    }
+
+    let now = e.ledger().timestamp();
+    if now < tier.sale_start {
+        return Err(TicketError::SaleNotStarted);
+    }
+    if tier.sale_end != 0 && now >= tier.sale_end {
+        return Err(TicketError::SaleEnded);
+    }
+    if tier.phases.is_empty() {
+        return Ok(None);
+    }
+
+    let index = active_phase(e, tier).ok_or(TicketError::NoActiveSalePhase)?;
+    let phase = tier.phases.get_unchecked(index);
+    if phase.sold >= phase.allocation {
+        return Err(TicketError::PhaseSoldOut);
+    }
+    Ok(Some(index))
+}
+
+fn active_phase(e: &Env, tier: &Tier) -> Option<u32> {
+    let now = e.ledger().timestamp();
+    tier.phases
+        .iter()
+        .position(|phase| phase.start_time <= now && now < phase.end_time)
+        .map(|index| index as u32)
+}
+
+fn record_phase_sale(tier: &mut Tier, phase: Option<u32>) {
+    if let Some(index) = phase {
+        let mut running = tier.phases.get_unchecked(index);
+        running.sold += 1;
+        tier.phases.set(index, running);
+    }
+}
+
+// A running sale phase sets the price as bps of the base price. Otherwise the
+// tier's pricing curve applies.
+fn ticket_price(e: &Env, tier: &Tier, base_price: i128) -> i128 {
+    if let Some(index) = active_phase(e, tier) {
+        let phase = tier.phases.get_unchecked(index);
+        return base_price * phase.price_bps as i128 / 10000;
+    }
+
+    let event_start = read_event_info(e).map_or(0, |info| info.start_time);
+    let ctx = PricingContext {
+        minted: tier.minted,
+        max_supply: tier.max_supply,
+        now: e.ledger().timestamp(),
+        event_start,
+    };
+    tier.pricing.price(base_price, &ctx)
+}
+
+fn sell_ticket(
+    e: &Env,
+    buyer: &Address,
+    payment_token: &Address,
+    tier_symbol: &Symbol,
+    price_override: Option<i128>,
+) -> Result<u32, TicketError> {
+    let token_ids = sell_tickets(
+        e,
+        buyer,
+        buyer,
+        payment_token,
+        tier_symbol,
+        1,
+        price_override,
+    )?;
+    Ok(token_ids.get_unchecked(0))
+}
+
+// Takes payment into escrow and mints `quantity` tickets, enforcing the tier's
+// sale schedule, supply and wallet caps for the whole order. Each ticket is
+// priced as if bought one after another, so curve thresholds and phase
+// allocations crossed inside the order apply. `payer` funds the order and the
+// tickets go to `buyer`, whose wallet caps apply. Callers handle payer auth.
+// `price_override` skips the tier's pricing and accepted-token check for
+// issuer-approved sales.
+fn sell_tickets(
+    e: &Env,
+    payer: &Address,
+    buyer: &Address,
+    payment_token: &Address,
+    tier_symbol: &Symbol,
+    quantity: u32,
+    price_override: Option<i128>,
+) -> Result<Vec<u32>, TicketError> {
+    if quantity == 0 {
+        return Err(TicketError::InvalidQuantity);
+    }
+    ensure_not_cancelled(e)?;
+
+    let mut tier = read_tier(e, tier_symbol)?;
+    ensure_on_sale(e, &tier)?;
+    if quantity > tier.max_supply - tier.minted {
+        return Err(TicketError::ExceedsMaxSupply);
+    }
+    if wallet_allowance(e, buyer, tier_symbol, &tier)? < quantity {
+        return Err(TicketError::WalletLimitReached);
+    }
+
+    let base_price = match price_override {
+        Some(_) => 0,
+        None => read_tier_price(e, tier_symbol, payment_token)?,
+    };
+
+    let mut prices = Vec::new(e);
+    let mut total: i128 = 0;
+    for _ in 0..quantity {
+        let phase = ensure_on_sale(e, &tier)?;
+        let price = price_override.unwrap_or_else(|| ticket_price(e, &tier, base_price));
+        prices.push_back(price);
+        total += price;
+
+        tier.minted += 1;
+        record_phase_sale(&mut tier, phase);
+    }
+
+    // Process payment into escrow in one transfer. Fully discounted vouchers move no funds.
+    if total > 0 {
+        let token_client = token::Client::new(e, payment_token);
+        token_client.transfer(payer, &e.current_contract_address(), &total);
+        write_escrow(e, payment_token, read_escrow(e, payment_token) + total);
+    }
+    write_tier(e, tier_symbol, &mut tier);
+
+    let mut token_ids = Vec::new(e);
+    for price in prices.iter() {
+        let token_id = mint_ticket(
+            e,
+            buyer,
+            tier_symbol,
+            price,
+            Some(payment_token.clone()),
+            Some(payer.clone()),
+        );
+        record_wallet_purchase(e, buyer, tier_symbol);
+        token_ids.push_back(token_id);
+
+        TicketPurchased {
+            tier_symbol: tier_symbol.clone(),
+            buyer: buyer.clone(),
+            token_id,
+            price,
+            payment_token: payment_token.clone(),
+            payer: payer.clone(),
+        }
+        .publish(e);
+    }
+    Ok(token_ids)
+}
+
+fn read_tier_list(e: &Env) -> Vec<Symbol> {
+    get_persistent(e, &DataKey::TierList).unwrap_or(Vec::new(e))
+}
+
+// Owner and tier indexes hold live token ids and are kept in step with every
+// mint, burn, resale and upgrade. Each id sits in its own slot with a count
+// and a reverse position per token, so no entry grows with the index and
+// removal is a swap with the last slot.
+enum TicketIndex {
+    Owner(Address),
+    Tier(Symbol),
+}
+
+impl TicketIndex {
+    fn slot_key(&self, slot: u32) -> DataKey {
+        match self {
+            TicketIndex::Owner(owner) => DataKey::OwnerTicket(owner.clone(), slot),
+            TicketIndex::Tier(tier_symbol) => DataKey::TierTicket(tier_symbol.clone(), slot),
+        }
+    }
+
+    fn count_key(&self) -> DataKey {
+        match self {
+            TicketIndex::Owner(owner) => DataKey::OwnerTicketCount(owner.clone()),
+            TicketIndex::Tier(tier_symbol) => DataKey::TierTicketCount(tier_symbol.clone()),
+        }
+    }
+
+    // A ticket has one owner and one tier, so its position is keyed by id alone
+    fn pos_key(&self, token_id: u32) -> DataKey {
+        match self {
+            TicketIndex::Owner(_) => DataKey::OwnerTicketPos(token_id),
+            TicketIndex::Tier(_) => DataKey::TierTicketPos(token_id),
+        }
+    }
+}
+
+fn index_len(e: &Env, index: &TicketIndex) -> u32 {
+    get_persistent(e, &index.count_key()).unwrap_or(0)
+}
+
+fn index_add(e: &Env, index: &TicketIndex, token_id: u32) {
+    let len = index_len(e, index);
+    set_persistent(e, &index.slot_key(len), &token_id);
+    set_persistent(e, &index.pos_key(token_id), &len);
+    set_persistent(e, &index.count_key(), &(len + 1));
+}
+
+fn index_remove(e: &Env, index: &TicketIndex, token_id: u32) {
+    let Some(slot) = get_persistent::<u32>(e, &index.pos_key(token_id)) else {
+        return;
+    };
+    let last = index_len(e, index) - 1;
+    if slot != last {
+        if let Some(moved) = get_persistent::<u32>(e, &index.slot_key(last)) {
+            set_persistent(e, &index.slot_key(slot), &moved);
+            set_persistent(e, &index.pos_key(moved), &slot);
+        }
+    }
+    let storage = e.storage().persistent();
+    storage.remove(&index.slot_key(last));
+    storage.remove(&index.pos_key(token_id));
+    set_persistent(e, &index.count_key(), &last);
+}
+
+// Extends a ticket's slot in the index along with the index count
+fn index_extend(e: &Env, index: &TicketIndex, token_id: u32) {
+    if let Some(slot) = get_persistent::<u32>(e, &index.pos_key(token_id)) {
+        extend_persistent(e, &index.slot_key(slot));
+        extend_persistent(e, &index.count_key());
+    }
+}
+
+fn index_page(e: &Env, index: &TicketIndex, start: u32, limit: u32) -> Vec<u32> {
+    let (start, end) = page_bounds(index_len(e, index), start, limit);
+    let mut ids = Vec::new(e);
+    for slot in start..end {
+        if let Some(token_id) = get_persistent(e, &index.slot_key(slot)) {
+            ids.push_back(token_id);
+        }
+    }
+    ids
+}
+
+fn page<T>(items: &Vec<T>, start: u32, limit: u32) -> Vec<T> {
+    let (start, end) = page_bounds(items.len(), start, limit);
+    items.slice(start..end)
+}
+
+fn page_bounds(len: u32, start: u32, limit: u32) -> (u32, u32) {
+    let start = start.min(len);
+    let end = start.saturating_add(limit.min(MAX_PAGE_SIZE)).min(len);
+    (start, end)
+}
+
+// Plain purchase paths are closed for allowlisted and seated tiers, which sell
+// through `purchase_allowlisted` and `purchase_seat`
+fn ensure_open_sale(e: &Env, tier_symbol: &Symbol) -> Result<(), TicketError> {
+    if read_allowlist_root(e, tier_symbol).is_some() {
+        return Err(TicketError::AllowlistRequired);
+    }
+    if is_seated(e, tier_symbol) {
+        return Err(TicketError::SeatSelectionRequired);
+    }
+    Ok(())
+}
+
+fn read_sections(e: &Env, tier_symbol: &Symbol) -> Vec<Symbol> {
+    get_persistent(e, &DataKey::Sections(tier_symbol.clone())).unwrap_or(Vec::new(e))
+}
+
+fn read_section_seats(e: &Env, tier_symbol: &Symbol, section: &Symbol) -> Vec<Seat> {
+    get_persistent(
+        e,
+        &DataKey::SectionSeats(tier_symbol.clone(), section.clone()),
+    )
+    .unwrap_or(Vec::new(e))
+}
+
+fn is_seated(e: &Env, tier_symbol: &Symbol) -> bool {
+    !read_sections(e, tier_symbol).is_empty()
+}
+
+fn release_seat(e: &Env, tier_symbol: &Symbol, ticket: &Ticket) {
+    if let Some(seat) = &ticket.seat {
+        e.storage()
+            .persistent()
+            .remove(&DataKey::SeatHolder(tier_symbol.clone(), seat.clone()));
+    }
+}
+
+fn read_allowlist_root(e: &Env, tier_symbol: &Symbol) -> Option<BytesN<32>> {
+    get_persistent(e, &DataKey::AllowlistRoot(tier_symbol.clone()))
+}
+
+// How many more tickets of a tier `buyer` can purchase under the per-tier and
+// per-event wallet caps and the tier's remaining supply.
+fn wallet_allowance(
+    e: &Env,
+    buyer: &Address,
+    tier_symbol: &Symbol,
+    tier: &Tier,
+) -> Result<u32, TicketError> {
+    let remaining_supply = tier.max_supply.saturating_sub(tier.minted);
+    Ok(remaining_supply.min(wallet_cap_allowance(e, buyer, tier_symbol, tier)?))
+}
+
+// How many more tickets of a tier `buyer` may hold under the wallet caps alone
+fn wallet_cap_allowance(
+    e: &Env,
+    buyer: &Address,
+    tier_symbol: &Symbol,
+    tier: &Tier,
+) -> Result<u32, TicketError> {
+    let mut allowance = u32::MAX;
+
+    if tier.max_per_wallet > 0 {
+        let bought = read_wallet_count(
+            e,
+            &DataKey::TierPurchases(tier_symbol.clone(), buyer.clone()),
+        );
+        allowance = allowance.min(tier.max_per_wallet.saturating_sub(bought));
+    }
+
+    let event_info = read_event_info(e)?;
+    if event_info.max_per_wallet > 0 {
+        let bought = read_wallet_count(e, &DataKey::WalletPurchases(buyer.clone()));
+        allowance = allowance.min(event_info.max_per_wallet.saturating_sub(bought));
+    }
+    Ok(allowance)
+}
+
+fn read_wallet_count(e: &Env, key: &DataKey) -> u32 {
+    get_persistent(e, key).unwrap_or(0)
+}
+
+// Purchase counts are kept even while no cap is set, so a cap introduced
+// mid-sale still accounts for what each wallet already bought.
+fn record_wallet_purchase(e: &Env, buyer: &Address, tier_symbol: &Symbol) {
+    let tier_key = DataKey::TierPurchases(tier_symbol.clone(), buyer.clone());
+    let event_key = DataKey::WalletPurchases(buyer.clone());
+    set_persistent(e, &tier_key, &(read_wallet_count(e, &tier_key) + 1));
+    set_persistent(e, &event_key, &(read_wallet_count(e, &event_key) + 1));
+}
+
+fn release_wallet_purchase(e: &Env, owner: &Address, tier_symbol: &Symbol) {
+    let tier_key = DataKey::TierPurchases(tier_symbol.clone(), owner.clone());
+    let event_key = DataKey::WalletPurchases(owner.clone());
+    set_persistent(
+        e,
+        &tier_key,
+        &read_wallet_count(e, &tier_key).saturating_sub(1),
+    );
+    set_persistent(
+        e,
+        &event_key,
+        &read_wallet_count(e, &event_key).saturating_sub(1),
+    );
+}
+
+// What a holder-requested refund pays right now, or `None` once refunds have
+// closed. A cancelled event refunds in full with no fee.
+fn refund_amount(e: &Env, event_info: &EventInfo, ticket: &Ticket) -> Option<i128> {
+    if event_info.cancelled {
+        return Some(ticket.price_paid);
+    }
+
+    let now = e.ledger().timestamp();
+    let refund_bps = if event_info.refund_schedule.is_empty() {
+        (now <= event_info.refund_cutoff_time).then_some(10000)
+    } else {
+        event_info
+            .refund_schedule
+            .iter()
+            .find(|step| now <= step.until)
+            .map(|step| step.refund_bps)
+            .filter(|bps| *bps > 0)
+    }?;
+
+    let fee = match &event_info.cancellation_fee {
+        Some(CancellationFee::Fixed(amount)) => *amount,
+        Some(CancellationFee::Bps(bps)) => ticket.price_paid * *bps as i128 / 10000,
+        None => 0,
+    };
+    // A fee that eats the whole refund closes it; unpaid tickets can still be
+    // handed back for nothing
+    let net = ticket.price_paid * refund_bps as i128 / 10000 - fee;
+    if ticket.price_paid > 0 && net <= 0 {
+        return None;
+    }
+    Some(net.max(0))
+}
+
+// Pays `amount` out of escrow back to whoever paid for the ticket, in the token
+// it was bought with, then invalidates and burns the ticket. Whatever part of
+// the price is not refunded stays in escrow as organizer revenue.
+fn refund_ticket(
+    e: &Env,
+    owner: &Address,
+    token_id: u32,
+    ticket: &mut Ticket,
+    amount: i128,
+) -> Result<(), TicketError> {
+    if !ticket.is_valid {
+        return Err(TicketError::TicketInvalidated);
+    }
+    if ticket.checked_in_at.is_some() {
+        return Err(TicketError::AlreadyCheckedIn);
+    }
+
+    // Admin-minted tickets were never paid for and are simply burned
+    if let Some(payment_token) = &ticket.payment_token {
+        let escrow = read_escrow(e, payment_token);
+        if escrow < amount {
+            return Err(TicketError::InsufficientEscrow);
+        }
+        write_escrow(e, payment_token, escrow - amount);
+        release_wallet_purchase(e, owner, &ticket.tier_symbol);
+
+        let token_client = token::Client::new(e, payment_token);
+        let refunded_to = ticket.payer.clone().unwrap_or(owner.clone());
+        token_client.transfer(&e.current_contract_address(), &refunded_to, &amount);
+
+        TicketRefunded {
+            tier_symbol: ticket.tier_symbol.clone(),
+            owner: owner.clone(),
+            token_id,
+            amount,
+            payment_token: payment_token.clone(),
+            refunded_to,
+        }
+        .publish(e);
+    }
+
+    // A released seat goes back on sale, so the tier gets its supply back too
+    if ticket.seat.is_some() {
+        let mut tier = read_tier(e, &ticket.tier_symbol)?;
+        tier.minted = tier.minted.saturating_sub(1);
+        write_tier(e, &ticket.tier_symbol, &mut tier);
+    }
+
+    // Invalidate and Burn
+    release_seat(e, &ticket.tier_symbol, ticket);
+    e.storage().persistent().remove(&DataKey::Listing(token_id));
+    ticket.is_valid = false;
+    write_ticket(e, token_id, ticket);
+    Base::update(e, Some(owner), None, token_id);
+    index_remove(e, &TicketIndex::Owner(owner.clone()), token_id);
+    index_remove(e, &TicketIndex::Tier(ticket.tier_symbol.clone()), token_id);
+
+    TicketBurned {
+        tier_symbol: ticket.tier_symbol.clone(),
+        owner: owner.clone(),
+        token_id,
+    }
+    .publish(e);
+    Ok(())
+}
+
+// Mints the NFT and records its ticket. The ticket is keyed by the id the
+// NFT base assigns so the two can never drift apart.
+fn mint_ticket(
+    e: &Env,
+    to: &Address,
+    tier_symbol: &Symbol,
+    price_paid: i128,
+    payment_token: Option<Address>,
+    payer: Option<Address>,
+) -> u32 {
+    let token_id = Base::sequential_mint(e, to);
+    extend_ownership(e, to, token_id);
+    index_add(e, &TicketIndex::Owner(to.clone()), token_id);
+    index_add(e, &TicketIndex::Tier(tier_symbol.clone()), token_id);
+
+    let ticket = Ticket {
+        tier_symbol: tier_symbol.clone(),
+        purchase_time: e.ledger().timestamp(),
+        price_paid,
+        is_valid: true,
+        checked_in_at: None,
+        checked_in_by: None,
+        payment_token,
+        payer,
+        upgrades: Vec::new(e),
+        promo_code: None,
+        seat: None,
+    };
+    write_ticket(e, token_id, &ticket);
+    token_id
 }
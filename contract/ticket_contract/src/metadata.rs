@@ -0,0 +1,79 @@
+use soroban_sdk::{Env, String, SymbolStr, TryFromVal};
+
+use crate::storage_types::Ticket;
+
+// Tier metadata URIs are capped so a full ticket URI always fits the buffer
+pub const MAX_METADATA_URI_LEN: u32 = 200;
+const MAX_TICKET_URI_LEN: usize = 512;
+
+// `{prefix}{token_id}?tier={symbol}&status={valid|checked_in|invalid}&purchased={timestamp}`
+// so wallets and marketplaces can show a ticket's state without calling the contract.
+pub fn ticket_uri(e: &Env, prefix: &String, token_id: u32, ticket: &Ticket) -> String {
+    let mut uri = UriBuf::new();
+    uri.push_string(prefix);
+    uri.push_u64(token_id as u64);
+
+    uri.push(b"?tier=");
+    if let Ok(symbol) = SymbolStr::try_from_val(e, &ticket.tier_symbol.to_symbol_val()) {
+        uri.push(AsRef::<[u8]>::as_ref(&symbol));
+    }
+
+    uri.push(b"&status=");
+    let status: &[u8] = if !ticket.is_valid {
+        b"invalid"
+    } else if ticket.checked_in_at.is_some() {
+        b"checked_in"
+    } else {
+        b"valid"
+    };
+    uri.push(status);
+
+    uri.push(b"&purchased=");
+    uri.push_u64(ticket.purchase_time);
+
+    String::from_bytes(e, uri.as_slice())
+}
+
+struct UriBuf {
+    buf: [u8; MAX_TICKET_URI_LEN],
+    len: usize,
+}
+
+impl UriBuf {
+    fn new() -> Self {
+        UriBuf {
+            buf: [0; MAX_TICKET_URI_LEN],
+            len: 0,
+        }
+    }
+
+    fn push(&mut self, bytes: &[u8]) {
+        let end = self.len + bytes.len();
+        self.buf[self.len..end].copy_from_slice(bytes);
+        self.len = end;
+    }
+
+    fn push_string(&mut self, s: &String) {
+        let end = self.len + s.len() as usize;
+        s.copy_into_slice(&mut self.buf[self.len..end]);
+        self.len = end;
+    }
+
+    fn push_u64(&mut self, mut value: u64) {
+        let mut digits = [0u8; 20];
+        let mut start = digits.len();
+        loop {
+            start -= 1;
+            digits[start] = b'0' + (value % 10) as u8;
+            value /= 10;
+            if value == 0 {
+                break;
+            }
+        }
+        self.push(&digits[start..]);
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        &self.buf[..self.len]
+    }
+}
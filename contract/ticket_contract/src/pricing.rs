@@ -0,0 +1,120 @@
+use soroban_sdk::contracttype;
+
+const BPS: i128 = 10000;
+
+// How a tier's price moves away from its base price. All curves are expressed
+// relative to the base price so one curve serves every accepted payment token.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum PricingCurve {
+    // Always the base price
+    Flat,
+    // Price rises by `increase_bps` each time another 1/`steps` of supply sells
+    Step(StepCurve),
+    // Price rises by `increase_bps` for every ticket already minted
+    Linear(LinearCurve),
+    // Discounted by `discount_bps` at `starts_at`, rising linearly to the base
+    // price at event start
+    EarlyBird(EarlyBirdCurve),
+    // Starts at `start_bps` of the base price at `starts_at` and falls linearly
+    // to `floor_bps` at `ends_at`
+    DutchAuction(DutchAuctionCurve),
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StepCurve {
+    pub steps: u32,
+    pub increase_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct LinearCurve {
+    pub increase_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyBirdCurve {
+    pub discount_bps: u32,
+    pub starts_at: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DutchAuctionCurve {
+    pub start_bps: u32,
+    pub floor_bps: u32,
+    pub starts_at: u64,
+    pub ends_at: u64,
+}
+
+// Inputs a curve may depend on at the time of pricing
+pub struct PricingContext {
+    pub minted: u32,
+    pub max_supply: u32,
+    pub now: u64,
+    pub event_start: u64,
+}
+
+impl PricingCurve {
+    // The original pricing: 5% more for each fifth of supply sold
+    pub fn default_step() -> Self {
+        PricingCurve::Step(StepCurve {
+            steps: 5,
+            increase_bps: 500,
+        })
+    }
+
+    pub fn is_valid(&self) -> bool {
+        match self {
+            PricingCurve::Flat | PricingCurve::Linear(_) => true,
+            PricingCurve::Step(curve) => curve.steps > 0,
+            PricingCurve::EarlyBird(curve) => curve.discount_bps as i128 <= BPS,
+            PricingCurve::DutchAuction(curve) => {
+                curve.ends_at > curve.starts_at && curve.floor_bps <= curve.start_bps
+            }
+        }
+    }
+
+    pub fn price(&self, base_price: i128, ctx: &PricingContext) -> i128 {
+        match self {
+            PricingCurve::Flat => base_price,
+            PricingCurve::Step(curve) => {
+                let step_size = (ctx.max_supply.max(1) / curve.steps.max(1)).max(1);
+                let thresholds_passed = (ctx.minted / step_size) as i128;
+                base_price + base_price * curve.increase_bps as i128 * thresholds_passed / BPS
+            }
+            PricingCurve::Linear(curve) => {
+                base_price + base_price * curve.increase_bps as i128 * ctx.minted as i128 / BPS
+            }
+            PricingCurve::EarlyBird(curve) => {
+                if ctx.now >= ctx.event_start || curve.starts_at >= ctx.event_start {
+                    return base_price;
+                }
+                let discount_bps = if ctx.now <= curve.starts_at {
+                    curve.discount_bps as i128
+                } else {
+                    let remaining = (ctx.event_start - ctx.now) as i128;
+                    let window = (ctx.event_start - curve.starts_at) as i128;
+                    curve.discount_bps as i128 * remaining / window
+                };
+                base_price * (BPS - discount_bps) / BPS
+            }
+            PricingCurve::DutchAuction(curve) => {
+                let bps = if ctx.now <= curve.starts_at {
+                    curve.start_bps as i128
+                } else if ctx.now >= curve.ends_at {
+                    curve.floor_bps as i128
+                } else {
+                    let elapsed = (ctx.now - curve.starts_at) as i128;
+                    let window = (curve.ends_at - curve.starts_at) as i128;
+                    let drop = (curve.start_bps - curve.floor_bps) as i128;
+                    curve.start_bps as i128 - drop * elapsed / window
+                };
+                base_price * bps / BPS
+            }
+        }
+    }
+}
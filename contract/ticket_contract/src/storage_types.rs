@@ -1,20 +1,63 @@
-use soroban_sdk::{contracttype, String, Symbol};
+use soroban_sdk::{contracttype, Address, BytesN, String, Symbol, Vec};
+
+use crate::pricing::PricingCurve;
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub enum DataKey {
-    Admin,
     EventInfo,
-    TokenIdCounter,
     Tier(Symbol),
     Ticket(u32),
+    Payout,
+    Escrow(Address),
+    TierPrice(Symbol, Address),
+    TierPurchases(Symbol, Address),
+    WalletPurchases(Address),
+    AllowlistRoot(Symbol),
+    AllowlistClaimed(Symbol, Address),
+    VoucherSigner,
+    VoucherNonce(u64),
+    ResalePolicy,
+    Listing(u32),
+    PromoCode(BytesN<32>),
+    PromoUses(BytesN<32>, Address),
+    Sections(Symbol),
+    SectionSeats(Symbol, Symbol),
+    SeatHolder(Symbol, Seat),
+    TierList,
+    OwnerTicket(Address, u32),
+    OwnerTicketCount(Address),
+    OwnerTicketPos(u32),
+    TierTicket(Symbol, u32),
+    TierTicketCount(Symbol),
+    TierTicketPos(u32),
 }
 
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct EventInfo {
     pub start_time: u64,
+    pub end_time: u64,
     pub refund_cutoff_time: u64,
+    pub allow_reentry: bool,
+    pub cancelled: bool,
+    pub max_per_wallet: u32,
+    pub refund_schedule: Vec<RefundStep>,
+    pub cancellation_fee: Option<CancellationFee>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RefundStep {
+    pub until: u64,
+    pub refund_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum CancellationFee {
+    Fixed(i128),
+    Bps(u32),
 }
 
 #[contracttype]
@@ -26,6 +69,24 @@ pub struct Tier {
     pub max_supply: u32,
     pub minted: u32,
     pub active: bool,
+    pub retired: bool,
+    pub sale_start: u64,
+    pub sale_end: u64,
+    pub phases: Vec<SalePhase>,
+    pub pricing: PricingCurve,
+    pub max_per_wallet: u32,
+    pub metadata_uri: Option<String>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SalePhase {
+    pub name: Symbol,
+    pub start_time: u64,
+    pub end_time: u64,
+    pub price_bps: u32,
+    pub allocation: u32,
+    pub sold: u32,
 }
 
 #[contracttype]
@@ -35,4 +96,76 @@ pub struct Ticket {
     pub purchase_time: u64,
     pub price_paid: i128,
     pub is_valid: bool,
+    pub checked_in_at: Option<u64>,
+    pub checked_in_by: Option<Address>,
+    pub payment_token: Option<Address>,
+    pub payer: Option<Address>,
+    pub upgrades: Vec<TicketUpgrade>,
+    pub promo_code: Option<BytesN<32>>,
+    pub seat: Option<Seat>,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Seat {
+    pub section: Symbol,
+    pub row: u32,
+    pub number: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TicketUpgrade {
+    pub from_tier: Symbol,
+    pub to_tier: Symbol,
+    pub amount_paid: i128,
+    pub upgraded_at: u64,
+}
+
+// A purchase authorized off-chain by the voucher signer
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Voucher {
+    pub buyer: Address,
+    pub tier_symbol: Symbol,
+    pub payment_token: Address,
+    pub price: i128,
+    pub expires_at: u64,
+    pub nonce: u64,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResalePolicy {
+    pub enabled: bool,
+    pub max_price_bps: u32,
+    pub royalty_bps: u32,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct Listing {
+    pub seller: Address,
+    pub price: i128,
+}
+
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum Discount {
+    // Basis points off the ticket price
+    Percent(u32),
+    // Flat amount off, in the payment token's units
+    Fixed(i128),
+}
+
+// Limits of 0 and an empty tier list mean unrestricted
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PromoCode {
+    pub discount: Discount,
+    pub tiers: Vec<Symbol>,
+    pub max_uses: u32,
+    pub uses: u32,
+    pub max_per_wallet: u32,
+    pub expires_at: u64,
 }
@@ -2,7 +2,12 @@
 extern crate std;
 
 use super::*;
-use soroban_sdk::{testutils::Address as _, Address, Env, String, Symbol};
+use crate::pricing::{DutchAuctionCurve, EarlyBirdCurve, LinearCurve, StepCurve};
+use ed25519_dalek::{Signer, SigningKey};
+use soroban_sdk::{
+    testutils::{storage::Persistent as _, Address as _, Events, Ledger},
+    token, vec, Address, BytesN, Env, Event, String, Symbol,
+};
 
 fn create_contract(e: &Env, admin: &Address) -> SoulboundTicketContractClient<'static> {
     let contract_id = e.register_contract(None, SoulboundTicketContract);
@@ -14,11 +19,25 @@ fn create_contract(e: &Env, admin: &Address) -> SoulboundTicketContractClient<'s
         &String::from_str(e, "TKT"),
         &String::from_str(e, "https://example.com"),
         &e.ledger().timestamp(),
+        &(e.ledger().timestamp() + 200000), // Event end
         &(e.ledger().timestamp() + 100000), // Refund cutoff
     );
     client
 }
 
+fn create_token<'a>(e: &Env, admin: &Address) -> token::StellarAssetClient<'a> {
+    let sac = e.register_stellar_asset_contract_v2(admin.clone());
+    token::StellarAssetClient::new(e, &sac.address())
+}
+
+fn assert_last_event(e: &Env, contract: &Address, event: impl Event) {
+    let last = e.events().all().last().unwrap();
+    assert_eq!(
+        vec![e, last],
+        vec![e, (contract.clone(), event.topics(e), event.data(e))]
+    );
+}
+
 #[test]
 fn test_initialize_and_tier_creation() {
     let e = Env::default();
@@ -27,7 +46,14 @@ fn test_initialize_and_tier_creation() {
     let client = create_contract(&e, &admin);
 
     let tier_sym = Symbol::new(&e, "VIP");
-    client.add_tier(&tier_sym, &String::from_str(&e, "VIP Ticket"), &100, &50);
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "VIP Ticket"),
+        &100,
+        &50,
+        &PricingCurve::default_step(),
+    );
 
     let price = client.get_ticket_price(&tier_sym);
     assert_eq!(price, 100);
@@ -43,9 +69,16 @@ fn test_batch_mint() {
     let client = create_contract(&e, &admin);
 
     let tier_sym = Symbol::new(&e, "GEN");
-    client.add_tier(&tier_sym, &String::from_str(&e, "General"), &50, &100);
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &50,
+        &100,
+        &PricingCurve::default_step(),
+    );
 
-    client.batch_mint(&user, &tier_sym, &5);
+    client.batch_mint(&admin, &user, &tier_sym, &5);
 
     let balance = client.balance(&user);
     assert_eq!(balance, 5);
@@ -55,7 +88,6 @@ fn test_batch_mint() {
 }
 
 #[test]
-#[should_panic(expected = "Soulbound: Tickets cannot be transferred")]
 fn test_soulbound_restriction() {
     let e = Env::default();
     e.mock_all_auths();
@@ -66,11 +98,24 @@ fn test_soulbound_restriction() {
     let client = create_contract(&e, &admin);
 
     let tier_sym = Symbol::new(&e, "VIP");
-    client.add_tier(&tier_sym, &String::from_str(&e, "VIP"), &100, &10);
-    client.batch_mint(&user1, &tier_sym, &1);
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "VIP"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.batch_mint(&admin, &user1, &tier_sym, &1);
 
-    // This should panic
-    client.transfer(&user1, &user2, &1);
+    assert_eq!(
+        client.try_transfer(&user1, &user2, &1),
+        Err(Ok(TicketError::NonTransferable.into()))
+    );
+    assert_eq!(
+        client.try_approve(&user1, &user2, &1, &1000),
+        Err(Ok(TicketError::ApprovalDisabled.into()))
+    );
 }
 
 #[test]
@@ -83,20 +128,2038 @@ fn test_dynamic_pricing() {
     let client = create_contract(&e, &admin);
 
     let tier_sym = Symbol::new(&e, "GEN");
-    client.add_tier(&tier_sym, &String::from_str(&e, "General"), &100, &10); // thresholds every 2 tickets
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    ); // thresholds every 2 tickets
 
     // Initial price should be base
     assert_eq!(client.get_ticket_price(&tier_sym), 100);
 
     // Mint 2 tickets (hits 20% threshold, max_supply=10, 10/5=2)
-    client.batch_mint(&user, &tier_sym, &2);
+    client.batch_mint(&admin, &user, &tier_sym, &2);
 
     // Price should increase by 5%
     assert_eq!(client.get_ticket_price(&tier_sym), 105);
 
     // Mint 2 more (hits 40%)
-    client.batch_mint(&user, &tier_sym, &2);
+    client.batch_mint(&admin, &user, &tier_sym, &2);
 
     // Price should increase by 10%
     assert_eq!(client.get_ticket_price(&tier_sym), 110);
 }
+
+fn add_priced_tier(
+    e: &Env,
+    client: &SoulboundTicketContractClient,
+    admin: &Address,
+    pricing: PricingCurve,
+) -> Symbol {
+    let tier_sym = Symbol::new(e, "GEN");
+    client.add_tier(
+        admin,
+        &tier_sym,
+        &String::from_str(e, "General"),
+        &1000,
+        &10,
+        &pricing,
+    );
+    tier_sym
+}
+
+#[test]
+fn test_flat_pricing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let tier_sym = add_priced_tier(&e, &client, &admin, PricingCurve::Flat);
+
+    client.batch_mint(&admin, &admin, &tier_sym, &9);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1000);
+}
+
+#[test]
+fn test_configurable_step_pricing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let curve = PricingCurve::Step(StepCurve {
+        steps: 2,
+        increase_bps: 2500,
+    });
+    let tier_sym = add_priced_tier(&e, &client, &admin, curve);
+
+    // Steps every 5 tickets, 25% each
+    client.batch_mint(&admin, &admin, &tier_sym, &4);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1000);
+    client.batch_mint(&admin, &admin, &tier_sym, &1);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1250);
+
+    let invalid = PricingCurve::Step(StepCurve {
+        steps: 0,
+        increase_bps: 2500,
+    });
+    assert_eq!(
+        client.try_add_tier(
+            &admin,
+            &Symbol::new(&e, "BAD"),
+            &String::from_str(&e, "Bad"),
+            &1000,
+            &10,
+            &invalid
+        ),
+        Err(Ok(TicketError::InvalidPricingCurve))
+    );
+}
+
+#[test]
+fn test_linear_pricing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let curve = PricingCurve::Linear(LinearCurve { increase_bps: 100 });
+    let tier_sym = add_priced_tier(&e, &client, &admin, curve);
+
+    // 1% more per ticket already minted
+    client.batch_mint(&admin, &admin, &tier_sym, &3);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1030);
+    client.batch_mint(&admin, &admin, &tier_sym, &1);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1040);
+}
+
+#[test]
+fn test_early_bird_pricing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    // Event starts at 10_000
+    e.ledger().with_mut(|li| li.timestamp = 10_000);
+    let admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let curve = PricingCurve::EarlyBird(EarlyBirdCurve {
+        discount_bps: 4000,
+        starts_at: 2_000,
+    });
+    let tier_sym = add_priced_tier(&e, &client, &admin, curve);
+
+    // Full 40% discount until the curve starts, then it shrinks toward start
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 600);
+    e.ledger().with_mut(|li| li.timestamp = 6_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 800);
+    e.ledger().with_mut(|li| li.timestamp = 10_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1000);
+}
+
+#[test]
+fn test_dutch_auction_pricing() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &10_000);
+
+    let curve = PricingCurve::DutchAuction(DutchAuctionCurve {
+        start_bps: 20000,
+        floor_bps: 5000,
+        starts_at: 1_000,
+        ends_at: 4_000,
+    });
+    let tier_sym = add_priced_tier(&e, &client, &admin, curve);
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &1000);
+
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 2000);
+    e.ledger().with_mut(|li| li.timestamp = 2_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 1500);
+    assert_eq!(client.get_tier(&tier_sym).current_price, 1500);
+
+    // Buyers pay the price at the time of purchase
+    client.purchase(&buyer, &token.address, &tier_sym);
+    assert_eq!(token.balance(&buyer), 8_500);
+
+    e.ledger().with_mut(|li| li.timestamp = 9_000);
+    assert_eq!(client.get_ticket_price(&tier_sym), 500);
+    // The tier view follows the curve without any write in between
+    assert_eq!(client.get_tier(&tier_sym).current_price, 500);
+}
+
+#[test]
+fn test_initialize_twice_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+
+    let result = client.try_initialize(
+        &admin,
+        &String::from_str(&e, "EventTicket"),
+        &String::from_str(&e, "TKT"),
+        &String::from_str(&e, "https://example.com"),
+        &0,
+        &0,
+        &0,
+    );
+    assert_eq!(result, Err(Ok(TicketError::AlreadyInitialized)));
+}
+
+#[test]
+fn test_tier_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+
+    let tier_sym = Symbol::new(&e, "VIP");
+    let missing = Symbol::new(&e, "NOPE");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "VIP"),
+        &100,
+        &2,
+        &PricingCurve::default_step(),
+    );
+
+    assert_eq!(
+        client.try_add_tier(
+            &admin,
+            &tier_sym,
+            &String::from_str(&e, "VIP"),
+            &100,
+            &2,
+            &PricingCurve::default_step()
+        ),
+        Err(Ok(TicketError::TierAlreadyExists))
+    );
+    assert_eq!(
+        client.try_get_ticket_price(&missing),
+        Err(Ok(TicketError::TierNotFound))
+    );
+    assert_eq!(
+        client.try_batch_mint(&admin, &user, &tier_sym, &3),
+        Err(Ok(TicketError::ExceedsMaxSupply))
+    );
+    assert_eq!(
+        client.try_get_ticket(&42),
+        Err(Ok(TicketError::TicketNotFound))
+    );
+}
+
+#[test]
+fn test_purchase_and_refund_errors() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &1,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    let token_id = client.purchase(&buyer, &token.address, &tier_sym);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::TierSoldOut))
+    );
+    assert_eq!(
+        client.try_refund(&other, &token_id),
+        Err(Ok(TicketError::NotTicketOwner))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += 100001);
+    assert_eq!(
+        client.try_refund(&buyer, &token_id),
+        Err(Ok(TicketError::RefundWindowClosed))
+    );
+
+    // A refunded ticket is burned but still reports why it cannot be refunded
+    client.issue_refund(&admin, &token_id);
+    assert_eq!(
+        client.try_refund(&buyer, &token_id),
+        Err(Ok(TicketError::TicketInvalidated))
+    );
+    assert_eq!(
+        client.try_issue_refund(&admin, &token_id),
+        Err(Ok(TicketError::TicketInvalidated))
+    );
+}
+
+#[test]
+fn test_lifecycle_events() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    let name = String::from_str(&e, "General");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &name,
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    assert_last_event(
+        &e,
+        &client.address,
+        TierCreated {
+            tier_symbol: tier_sym.clone(),
+            name,
+            base_price: 100,
+            max_supply: 10,
+            pricing: PricingCurve::default_step(),
+        },
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    let token_id = client.purchase(&buyer, &token.address, &tier_sym);
+    assert_last_event(
+        &e,
+        &client.address,
+        TicketPurchased {
+            tier_symbol: tier_sym.clone(),
+            buyer: buyer.clone(),
+            token_id,
+            price: 100,
+            payment_token: token.address.clone(),
+            payer: buyer.clone(),
+        },
+    );
+
+    client.batch_mint(&admin, &buyer, &tier_sym, &1);
+    assert_last_event(
+        &e,
+        &client.address,
+        TicketMinted {
+            tier_symbol: tier_sym.clone(),
+            to: buyer.clone(),
+            token_id: token_id + 1,
+        },
+    );
+
+    client.refund(&buyer, &token_id);
+    assert_last_event(
+        &e,
+        &client.address,
+        TicketBurned {
+            tier_symbol: tier_sym,
+            owner: buyer,
+            token_id,
+        },
+    );
+}
+
+#[test]
+fn test_check_in() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let scanner = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&user, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &50,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &50);
+    let token_id = client.purchase(&user, &token.address, &tier_sym);
+
+    assert_eq!(
+        client.try_check_in(&scanner, &token_id),
+        Err(Ok(TicketError::UnauthorizedScanner))
+    );
+
+    client.grant_role(&scanner, &SCANNER, &admin);
+    assert!(!client.is_checked_in(&token_id));
+    client.check_in(&scanner, &token_id);
+    assert!(client.is_checked_in(&token_id));
+
+    let ticket = client.get_ticket(&token_id);
+    assert_eq!(ticket.checked_in_at, Some(e.ledger().timestamp()));
+    assert_eq!(ticket.checked_in_by, Some(scanner.clone()));
+
+    // No double entry unless the organizer allows re-entry
+    assert_eq!(
+        client.try_check_in(&scanner, &token_id),
+        Err(Ok(TicketError::AlreadyCheckedIn))
+    );
+    client.set_reentry_policy(&admin, &true);
+    assert_last_event(
+        &e,
+        &client.address,
+        ReentryPolicySet {
+            allow_reentry: true,
+        },
+    );
+    client.check_in(&scanner, &token_id);
+
+    // Doors close at event end
+    e.ledger().with_mut(|li| li.timestamp += 200001);
+    assert_eq!(
+        client.try_check_in(&scanner, &token_id),
+        Err(Ok(TicketError::CheckInClosed))
+    );
+}
+
+#[test]
+fn test_check_in_before_doors_open() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000_000);
+
+    let admin = Address::generate(&e);
+    let user = Address::generate(&e);
+    let scanner = Address::generate(&e);
+    let contract_id = e.register_contract(None, SoulboundTicketContract);
+    let client = SoulboundTicketContractClient::new(&e, &contract_id);
+    client.initialize(
+        &admin,
+        &String::from_str(&e, "EventTicket"),
+        &String::from_str(&e, "TKT"),
+        &String::from_str(&e, "https://example.com"),
+        &(1_000_000 + CHECK_IN_OPENS_BEFORE + 1), // Start
+        &2_000_000,                               // End
+        &1_000_000,                               // Refund cutoff
+    );
+    client.grant_role(&scanner, &SCANNER, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&user, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &50,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &50);
+    let token_id = client.purchase(&user, &token.address, &tier_sym);
+
+    assert_eq!(
+        client.try_check_in(&scanner, &token_id),
+        Err(Ok(TicketError::CheckInClosed))
+    );
+}
+
+#[test]
+fn test_role_gating() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let organizer = Address::generate(&e);
+    let minter = Address::generate(&e);
+    let finance = Address::generate(&e);
+    let user = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&user, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    let name = String::from_str(&e, "General");
+    assert_eq!(
+        client.try_add_tier(
+            &organizer,
+            &tier_sym,
+            &name,
+            &100,
+            &10,
+            &PricingCurve::default_step()
+        ),
+        Err(Ok(TicketError::Unauthorized))
+    );
+
+    client.grant_role(&organizer, &ORGANIZER, &admin);
+    client.grant_role(&minter, &MINTER, &admin);
+    client.grant_role(&finance, &FINANCE, &admin);
+
+    client.add_tier(
+        &organizer,
+        &tier_sym,
+        &name,
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&organizer, &tier_sym, &token.address, &100);
+    assert_eq!(
+        client.try_batch_mint(&organizer, &user, &tier_sym, &1),
+        Err(Ok(TicketError::Unauthorized))
+    );
+    client.batch_mint(&minter, &user, &tier_sym, &1);
+    assert_eq!(client.balance(&user), 1);
+
+    // Finance can refund outside the refund window
+    let token_id = client.purchase(&user, &token.address, &tier_sym);
+    e.ledger().with_mut(|li| li.timestamp += 100001);
+    assert_eq!(
+        client.try_issue_refund(&minter, &token_id),
+        Err(Ok(TicketError::Unauthorized))
+    );
+    client.issue_refund(&finance, &token_id);
+    assert!(!client.validate_ticket(&token_id));
+    assert_eq!(token.balance(&user), 1_000);
+}
+
+#[test]
+fn test_ownership_transfer_moves_admin_rights() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let new_admin = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    assert_eq!(client.get_owner(), Some(admin.clone()));
+
+    client.transfer_ownership(&new_admin, &1000);
+    client.accept_ownership();
+    assert_eq!(client.get_owner(), Some(new_admin.clone()));
+    assert_eq!(client.get_admin(), Some(new_admin.clone()));
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    let name = String::from_str(&e, "General");
+    assert_eq!(
+        client.try_add_tier(
+            &admin,
+            &tier_sym,
+            &name,
+            &100,
+            &10,
+            &PricingCurve::default_step()
+        ),
+        Err(Ok(TicketError::Unauthorized))
+    );
+    client.add_tier(
+        &new_admin,
+        &tier_sym,
+        &name,
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+}
+
+#[test]
+fn test_escrow_and_settlement() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payout = Address::generate(&e);
+    let buyer1 = Address::generate(&e);
+    let buyer2 = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer1, &1_000);
+    token.mint(&buyer2, &1_000);
+    client.set_payout_address(&admin, &payout);
+    assert_last_event(
+        &e,
+        &client.address,
+        PayoutAddressSet {
+            caller: admin.clone(),
+            payout: payout.clone(),
+        },
+    );
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &100,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    // Proceeds are held by the contract, not the organizer
+    let token_id = client.purchase(&buyer1, &token.address, &tier_sym);
+    client.purchase(&buyer2, &token.address, &tier_sym);
+    assert_eq!(token.balance(&client.address), 200);
+    assert_eq!(token.balance(&admin), 0);
+    assert_eq!(client.escrow_balance(&token.address), 200);
+
+    // Refunds come out of escrow
+    client.refund(&buyer1, &token_id);
+    assert_eq!(token.balance(&buyer1), 1_000);
+    assert_eq!(client.escrow_balance(&token.address), 100);
+
+    // Settlement opens only after event end plus the dispute window
+    e.ledger()
+        .with_mut(|li| li.timestamp = 200000 + DISPUTE_WINDOW);
+    assert_eq!(
+        client.try_settle(&admin, &token.address),
+        Err(Ok(TicketError::SettlementLocked))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp += 1);
+    assert_eq!(client.settle(&admin, &token.address), 100);
+    assert_eq!(token.balance(&payout), 100);
+    assert_eq!(client.escrow_balance(&token.address), 0);
+    assert_eq!(
+        client.try_settle(&admin, &token.address),
+        Err(Ok(TicketError::NothingToSettle))
+    );
+}
+
+#[test]
+fn test_settlement_lock_does_not_overflow() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let contract_id = e.register_contract(None, SoulboundTicketContract);
+    let client = SoulboundTicketContractClient::new(&e, &contract_id);
+    client.initialize(
+        &admin,
+        &String::from_str(&e, "EventTicket"),
+        &String::from_str(&e, "TKT"),
+        &String::from_str(&e, "https://example.com"),
+        &0,
+        &(u64::MAX - 1),
+        &0,
+    );
+    let token = create_token(&e, &admin);
+
+    // end_time + DISPUTE_WINDOW would wrap and unlock settlement
+    assert_eq!(
+        client.try_settle(&admin, &token.address),
+        Err(Ok(TicketError::SettlementLocked))
+    );
+}
+
+#[test]
+fn test_payment_token_recorded_and_enforced() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let usdc = create_token(&e, &admin);
+    let xlm = create_token(&e, &admin);
+    let junk = create_token(&e, &admin);
+    usdc.mint(&buyer, &1_000);
+    xlm.mint(&buyer, &10_000);
+    junk.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &usdc.address, &100);
+    client.set_accepted_token(&admin, &tier_sym, &xlm.address, &800);
+
+    assert_eq!(
+        client.try_purchase(&buyer, &junk.address, &tier_sym),
+        Err(Ok(TicketError::PaymentTokenNotAccepted))
+    );
+    assert_eq!(client.quote_price(&tier_sym, &xlm.address), 800);
+
+    let token_id = client.purchase(&buyer, &xlm.address, &tier_sym);
+    let ticket = client.get_ticket(&token_id);
+    assert_eq!(ticket.payment_token, Some(xlm.address.clone()));
+    assert_eq!(ticket.price_paid, 800);
+
+    // Refund always goes back in the recorded token and amount
+    client.refund(&buyer, &token_id);
+    assert_eq!(xlm.balance(&buyer), 10_000);
+    assert_eq!(usdc.balance(&buyer), 1_000);
+
+    client.remove_accepted_token(&admin, &tier_sym, &xlm.address);
+    assert_eq!(
+        client.try_purchase(&buyer, &xlm.address, &tier_sym),
+        Err(Ok(TicketError::PaymentTokenNotAccepted))
+    );
+
+    // Negative prices are rejected up front rather than failing in the token transfer
+    assert_eq!(
+        client.try_set_accepted_token(&admin, &tier_sym, &xlm.address, &-1),
+        Err(Ok(TicketError::InvalidPrice))
+    );
+    let tier = client.get_tier(&tier_sym);
+    assert_eq!(
+        client.try_update_tier(&admin, &tier_sym, &tier.name, &-1, &tier.max_supply),
+        Err(Ok(TicketError::InvalidPrice))
+    );
+    assert_eq!(
+        client.try_add_tier(
+            &admin,
+            &Symbol::new(&e, "NEG"),
+            &tier.name,
+            &-1,
+            &10,
+            &PricingCurve::Flat
+        ),
+        Err(Ok(TicketError::InvalidPrice))
+    );
+}
+
+#[test]
+fn test_cancel_event_refunds_in_full() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let scanner = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+    client.grant_role(&scanner, &SCANNER, &admin);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    let token_id = client.purchase(&buyer, &token.address, &tier_sym);
+
+    // Past the refund cutoff nothing is claimable until the event is cancelled
+    e.ledger().with_mut(|li| li.timestamp += 100001);
+    assert_eq!(client.claimable_refund(&token_id), 0);
+
+    client.cancel_event(&admin);
+    assert!(client.is_cancelled());
+    assert_last_event(
+        &e,
+        &client.address,
+        EventCancelled {
+            caller: admin.clone(),
+            cancelled_at: e.ledger().timestamp(),
+        },
+    );
+    assert_eq!(
+        client.try_cancel_event(&admin),
+        Err(Ok(TicketError::EventCancelled))
+    );
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::EventCancelled))
+    );
+    assert_eq!(
+        client.try_check_in(&scanner, &token_id),
+        Err(Ok(TicketError::EventCancelled))
+    );
+
+    assert_eq!(client.claimable_refund(&token_id), 100);
+    client.refund(&buyer, &token_id);
+    assert_eq!(token.balance(&buyer), 1_000);
+    assert_eq!(client.claimable_refund(&token_id), 0);
+}
+
+#[test]
+fn test_cancel_event_after_settlement_fails() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    client.purchase(&buyer, &token.address, &tier_sym);
+
+    // Once the event is over it can no longer be cancelled
+    e.ledger().with_mut(|li| li.timestamp += 200001);
+    assert_eq!(
+        client.try_cancel_event(&admin),
+        Err(Ok(TicketError::EventEnded))
+    );
+
+    // Which means holders can never be promised refunds from a settled escrow
+    e.ledger().with_mut(|li| li.timestamp += DISPUTE_WINDOW);
+    client.settle(&admin, &token.address);
+    assert_eq!(
+        client.try_cancel_event(&admin),
+        Err(Ok(TicketError::EventEnded))
+    );
+    assert!(!client.is_cancelled());
+}
+
+#[test]
+fn test_tier_management() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "Genral"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    client.batch_mint(&admin, &buyer, &tier_sym, &4);
+
+    // current_price tracks the dynamic price as tickets are minted
+    let tier = client.get_tier(&tier_sym);
+    assert_eq!(tier.current_price, client.get_ticket_price(&tier_sym));
+    assert_eq!(tier.current_price, 110);
+
+    assert_eq!(
+        client.try_update_tier(&admin, &tier_sym, &tier.name, &100, &3),
+        Err(Ok(TicketError::MaxSupplyBelowMinted))
+    );
+    let name = String::from_str(&e, "General");
+    client.update_tier(&admin, &tier_sym, &name, &200, &20);
+    let tier = client.get_tier(&tier_sym);
+    assert_eq!(tier.name, name);
+    assert_eq!(tier.max_supply, 20);
+    assert_eq!(tier.current_price, client.get_ticket_price(&tier_sym));
+
+    client.set_tier_active(&admin, &tier_sym, &false);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::TierNotActive))
+    );
+    client.set_tier_active(&admin, &tier_sym, &true);
+    client.purchase(&buyer, &token.address, &tier_sym);
+    // The reference price is display-only; buyers pay the accepted token's price
+    assert_eq!(token.balance(&buyer), 895);
+
+    client.retire_tier(&admin, &tier_sym);
+    assert!(!client.get_tier(&tier_sym).active);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::TierRetired))
+    );
+    assert_eq!(
+        client.try_set_tier_active(&admin, &tier_sym, &true),
+        Err(Ok(TicketError::TierRetired))
+    );
+}
+
+#[test]
+fn test_sale_schedule_and_phases() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    let presale = SalePhase {
+        name: Symbol::new(&e, "presale"),
+        start_time: 2_000,
+        end_time: 3_000,
+        price_bps: 8_000,
+        allocation: 1,
+        sold: 0,
+    };
+    let general = SalePhase {
+        name: Symbol::new(&e, "general"),
+        start_time: 4_000,
+        end_time: 5_000,
+        price_bps: 10_000,
+        allocation: 5,
+        sold: 0,
+    };
+
+    // Overlapping phases are rejected
+    let mut overlapping = presale.clone();
+    overlapping.end_time = 4_500;
+    assert_eq!(
+        client.try_set_sale_schedule(
+            &admin,
+            &tier_sym,
+            &2_000,
+            &5_000,
+            &vec![&e, overlapping, general.clone()]
+        ),
+        Err(Ok(TicketError::InvalidSaleWindow))
+    );
+
+    let phases = vec![&e, presale, general];
+    client.set_sale_schedule(&admin, &tier_sym, &2_000, &5_000, &phases);
+    assert_last_event(
+        &e,
+        &client.address,
+        SaleScheduleSet {
+            tier_symbol: tier_sym.clone(),
+            sale_start: 2_000,
+            sale_end: 5_000,
+            phases,
+        },
+    );
+
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::SaleNotStarted))
+    );
+
+    // Presale price is 80% of base, and only one ticket is allocated
+    e.ledger().with_mut(|li| li.timestamp = 2_500);
+    assert_eq!(client.get_ticket_price(&tier_sym), 80);
+    client.purchase(&buyer, &token.address, &tier_sym);
+    assert_eq!(token.balance(&buyer), 920);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::PhaseSoldOut))
+    );
+
+    // Editing the running presale keeps what it has already sold
+    let mut phases = client.get_tier(&tier_sym).phases;
+    let mut presale = phases.get_unchecked(0);
+    presale.end_time = 3_200;
+    presale.sold = 0;
+    phases.set(0, presale);
+    client.set_sale_schedule(&admin, &tier_sym, &2_000, &5_000, &phases);
+    assert_eq!(client.get_tier(&tier_sym).phases.get_unchecked(0).sold, 1);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::PhaseSoldOut))
+    );
+
+    // Gap between phases
+    e.ledger().with_mut(|li| li.timestamp = 3_500);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::NoActiveSalePhase))
+    );
+
+    e.ledger().with_mut(|li| li.timestamp = 4_000);
+    client.purchase(&buyer, &token.address, &tier_sym);
+    assert_eq!(token.balance(&buyer), 820);
+    assert_eq!(client.get_tier(&tier_sym).phases.get_unchecked(1).sold, 1);
+
+    e.ledger().with_mut(|li| li.timestamp = 5_000);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::SaleEnded))
+    );
+}
+
+#[test]
+fn test_wallet_caps() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+    token.mint(&other, &1_000);
+
+    let general = Symbol::new(&e, "GEN");
+    let vip = Symbol::new(&e, "VIP");
+    for tier_sym in [&general, &vip] {
+        client.add_tier(
+            &admin,
+            tier_sym,
+            &String::from_str(&e, "Tier"),
+            &100,
+            &10,
+            &PricingCurve::Flat,
+        );
+        client.set_accepted_token(&admin, tier_sym, &token.address, &100);
+    }
+
+    // Without caps a wallet may buy out the tier
+    assert_eq!(client.remaining_allowance(&buyer, &general), 10);
+
+    client.set_tier_wallet_cap(&admin, &general, &2);
+    assert_last_event(
+        &e,
+        &client.address,
+        TierWalletCapSet {
+            tier_symbol: general.clone(),
+            max_per_wallet: 2,
+        },
+    );
+    client.set_wallet_cap(&admin, &3);
+    assert_last_event(&e, &client.address, WalletCapSet { max_per_wallet: 3 });
+    let first = client.purchase(&buyer, &token.address, &general);
+    client.purchase(&buyer, &token.address, &general);
+    assert_eq!(client.remaining_allowance(&buyer, &general), 0);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &general),
+        Err(Ok(TicketError::WalletLimitReached))
+    );
+
+    // The event-wide cap spans tiers
+    assert_eq!(client.remaining_allowance(&buyer, &vip), 1);
+    client.purchase(&buyer, &token.address, &vip);
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &vip),
+        Err(Ok(TicketError::WalletLimitReached))
+    );
+
+    // Other wallets are unaffected
+    assert_eq!(client.remaining_allowance(&other, &general), 2);
+    client.purchase(&other, &token.address, &general);
+
+    // Refunds give the allowance back
+    client.refund(&buyer, &first);
+    assert_eq!(client.remaining_allowance(&buyer, &general), 1);
+    client.purchase(&buyer, &token.address, &general);
+}
+
+#[test]
+fn test_allowlist_purchase() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let friend = Address::generate(&e);
+    let stranger = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    for account in [&buyer, &friend, &stranger] {
+        token.mint(account, &1_000);
+    }
+
+    let tier_sym = Symbol::new(&e, "PRE");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "Presale"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    // buyer may buy 2, friend is unlimited
+    let buyer_leaf = allowlist::leaf(&e, &buyer, 2);
+    let friend_leaf = allowlist::leaf(&e, &friend, 0);
+    let filler = allowlist::leaf(&e, &admin, 1);
+    let node = allowlist::hash_pair(&e, &buyer_leaf, &friend_leaf);
+    let root = allowlist::hash_pair(&e, &node, &filler);
+    let buyer_proof = vec![&e, friend_leaf.clone(), filler.clone()];
+    let friend_proof = vec![&e, buyer_leaf, filler];
+
+    assert_eq!(
+        client.try_purchase_allowlisted(&buyer, &token.address, &tier_sym, &2, &buyer_proof),
+        Err(Ok(TicketError::AllowlistNotSet))
+    );
+    client.set_allowlist_root(&admin, &tier_sym, &Some(root.clone()));
+    assert_last_event(
+        &e,
+        &client.address,
+        AllowlistRootSet {
+            tier_symbol: tier_sym.clone(),
+            root: Some(root),
+        },
+    );
+
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::AllowlistRequired))
+    );
+
+    // Claiming a bigger allocation or someone else's proof fails
+    assert_eq!(
+        client.try_purchase_allowlisted(&buyer, &token.address, &tier_sym, &5, &buyer_proof),
+        Err(Ok(TicketError::InvalidProof))
+    );
+    assert_eq!(
+        client.try_purchase_allowlisted(&stranger, &token.address, &tier_sym, &2, &buyer_proof),
+        Err(Ok(TicketError::InvalidProof))
+    );
+
+    let token_id = client.purchase_allowlisted(&buyer, &token.address, &tier_sym, &2, &buyer_proof);
+    client.purchase_allowlisted(&buyer, &token.address, &tier_sym, &2, &buyer_proof);
+    assert_eq!(client.allowlist_claimed(&tier_sym, &buyer), 2);
+    assert_eq!(
+        client.try_purchase_allowlisted(&buyer, &token.address, &tier_sym, &2, &buyer_proof),
+        Err(Ok(TicketError::AllocationExhausted))
+    );
+
+    // A refund does not free up the allocation again
+    client.refund(&buyer, &token_id);
+    assert_eq!(
+        client.try_purchase_allowlisted(&buyer, &token.address, &tier_sym, &2, &buyer_proof),
+        Err(Ok(TicketError::AllocationExhausted))
+    );
+
+    for _ in 0..3 {
+        client.purchase_allowlisted(&friend, &token.address, &tier_sym, &0, &friend_proof);
+    }
+
+    // Clearing the root opens the tier to everyone
+    client.set_allowlist_root(&admin, &tier_sym, &None);
+    client.purchase(&stranger, &token.address, &tier_sym);
+}
+
+fn sign_voucher(e: &Env, contract: &Address, key: &SigningKey, voucher: &Voucher) -> BytesN<64> {
+    let payload = (contract.clone(), voucher.clone()).to_xdr(e);
+    let message: std::vec::Vec<u8> = payload.iter().collect();
+    BytesN::from_array(e, &key.sign(&message).to_bytes())
+}
+
+#[test]
+fn test_purchase_with_voucher() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+
+    let signer = SigningKey::from_bytes(&[7; 32]);
+    let public_key = BytesN::from_array(&e, &signer.verifying_key().to_bytes());
+    let voucher = Voucher {
+        buyer: buyer.clone(),
+        tier_symbol: tier_sym.clone(),
+        payment_token: token.address.clone(),
+        price: 40,
+        expires_at: e.ledger().timestamp() + 1_000,
+        nonce: 1,
+    };
+    let signature = sign_voucher(&e, &client.address, &signer, &voucher);
+
+    assert_eq!(
+        client.try_purchase_with_voucher(&voucher, &signature),
+        Err(Ok(TicketError::VoucherSignerNotSet))
+    );
+    client.set_voucher_signer(&admin, &Some(public_key.clone()));
+    assert_last_event(
+        &e,
+        &client.address,
+        VoucherSignerSet {
+            public_key: Some(public_key),
+        },
+    );
+
+    // The voucher price applies even though the token is not accepted for the tier
+    let token_id = client.purchase_with_voucher(&voucher, &signature);
+    assert_eq!(token.balance(&buyer), 960);
+    assert_eq!(client.get_ticket(&token_id).price_paid, 40);
+    assert!(client.is_voucher_used(&1));
+    assert_eq!(
+        client.try_purchase_with_voucher(&voucher, &signature),
+        Err(Ok(TicketError::VoucherAlreadyUsed))
+    );
+
+    // Tampering with the payload invalidates the signature
+    let mut tampered = voucher.clone();
+    tampered.nonce = 2;
+    tampered.price = 1;
+    assert!(client
+        .try_purchase_with_voucher(&tampered, &signature)
+        .is_err());
+    assert!(!client.is_voucher_used(&2));
+
+    let mut expired = voucher;
+    expired.nonce = 3;
+    let signature = sign_voucher(&e, &client.address, &signer, &expired);
+    e.ledger().with_mut(|li| li.timestamp += 1_001);
+    assert_eq!(
+        client.try_purchase_with_voucher(&expired, &signature),
+        Err(Ok(TicketError::VoucherExpired))
+    );
+}
+
+#[test]
+fn test_purchase_many() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &10_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::default_step(),
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    assert_eq!(
+        client.try_purchase_many(&buyer, &token.address, &tier_sym, &0),
+        Err(Ok(TicketError::InvalidQuantity))
+    );
+
+    // The price steps up after the second ticket, inside the order
+    let token_ids = client.purchase_many(&buyer, &token.address, &tier_sym, &3);
+    assert_eq!(token_ids.len(), 3);
+    assert_eq!(token.balance(&buyer), 10_000 - 305);
+    assert_eq!(client.escrow_balance(&token.address), 305);
+    assert_eq!(
+        client.get_ticket(&token_ids.get(2).unwrap()).price_paid,
+        105
+    );
+    assert_eq!(client.get_tier(&tier_sym).minted, 3);
+
+    assert_eq!(
+        client.try_purchase_many(&buyer, &token.address, &tier_sym, &8),
+        Err(Ok(TicketError::ExceedsMaxSupply))
+    );
+
+    // Orders over the wallet cap fail as a whole
+    client.set_tier_wallet_cap(&admin, &tier_sym, &4);
+    assert_eq!(
+        client.try_purchase_many(&buyer, &token.address, &tier_sym, &2),
+        Err(Ok(TicketError::WalletLimitReached))
+    );
+    assert_eq!(client.get_tier(&tier_sym).minted, 3);
+    client.purchase_many(&buyer, &token.address, &tier_sym, &1);
+}
+
+#[test]
+fn test_purchase_for_recipient() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let friend = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&payer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    client.set_tier_wallet_cap(&admin, &tier_sym, &1);
+
+    let token_id = client.purchase_for(&payer, &friend, &token.address, &tier_sym);
+    assert_eq!(client.owner_of(&token_id), friend);
+    assert_eq!(token.balance(&payer), 900);
+    let ticket = client.get_ticket(&token_id);
+    assert_eq!(ticket.payer, Some(payer.clone()));
+
+    // Wallet caps follow the recipient, not the payer
+    assert_eq!(client.remaining_allowance(&friend, &tier_sym), 0);
+    assert_eq!(client.remaining_allowance(&payer, &tier_sym), 1);
+    assert_eq!(
+        client.try_purchase_for(&payer, &friend, &token.address, &tier_sym),
+        Err(Ok(TicketError::WalletLimitReached))
+    );
+
+    // Only the holder can ask for a refund, but the payer gets the money back
+    assert_eq!(
+        client.try_refund(&payer, &token_id),
+        Err(Ok(TicketError::NotTicketOwner))
+    );
+    client.refund(&friend, &token_id);
+    assert_eq!(token.balance(&payer), 1_000);
+    assert_eq!(token.balance(&friend), 0);
+}
+
+#[test]
+fn test_refund_schedule_and_fee() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_000);
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    let first = client.purchase(&buyer, &token.address, &tier_sym);
+    let second = client.purchase(&buyer, &token.address, &tier_sym);
+    let third = client.purchase(&buyer, &token.address, &tier_sym);
+
+    let unordered = vec![
+        &e,
+        RefundStep {
+            until: 3_000,
+            refund_bps: 10000,
+        },
+        RefundStep {
+            until: 2_000,
+            refund_bps: 5000,
+        },
+    ];
+    assert_eq!(
+        client.try_set_refund_policy(&admin, &unordered, &None),
+        Err(Ok(TicketError::InvalidRefundPolicy))
+    );
+
+    // 100% until 2_000, 50% until 3_000, nothing after; 5 withheld per refund
+    let schedule = vec![
+        &e,
+        RefundStep {
+            until: 2_000,
+            refund_bps: 10000,
+        },
+        RefundStep {
+            until: 3_000,
+            refund_bps: 5000,
+        },
+    ];
+    let fee = Some(CancellationFee::Fixed(5));
+    client.set_refund_policy(&admin, &schedule, &fee);
+    assert_last_event(
+        &e,
+        &client.address,
+        RefundPolicySet {
+            schedule: schedule.clone(),
+            cancellation_fee: fee,
+        },
+    );
+
+    assert_eq!(client.quote_refund(&first), 95);
+    client.refund(&buyer, &first);
+    assert_eq!(token.balance(&buyer), 795);
+
+    e.ledger().with_mut(|li| li.timestamp = 2_500);
+    assert_eq!(client.quote_refund(&second), 45);
+    client.refund(&buyer, &second);
+    assert_eq!(token.balance(&buyer), 840);
+
+    // The withheld part stays in escrow for settlement
+    assert_eq!(client.escrow_balance(&token.address), 160);
+
+    e.ledger().with_mut(|li| li.timestamp = 3_001);
+    assert_eq!(client.quote_refund(&third), 0);
+    assert_eq!(
+        client.try_refund(&buyer, &third),
+        Err(Ok(TicketError::RefundWindowClosed))
+    );
+
+    // A fee at least as large as the refund closes the window
+    e.ledger().with_mut(|li| li.timestamp = 1_500);
+    client.set_refund_policy(&admin, &schedule, &Some(CancellationFee::Fixed(100)));
+    assert_eq!(client.quote_refund(&third), 0);
+    assert_eq!(
+        client.try_refund(&buyer, &third),
+        Err(Ok(TicketError::RefundWindowClosed))
+    );
+
+    // Cancellation still refunds in full with no fee
+    client.cancel_event(&admin);
+    assert_eq!(client.quote_refund(&third), 100);
+    client.refund(&buyer, &third);
+    assert_eq!(token.balance(&buyer), 940);
+}
+
+#[test]
+fn test_resale() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let seller = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&seller, &1_000);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    let token_id = client.purchase(&seller, &token.address, &tier_sym);
+
+    assert_eq!(
+        client.try_list_for_resale(&seller, &token_id, &100),
+        Err(Ok(TicketError::ResaleDisabled))
+    );
+    client.set_resale_policy(
+        &admin,
+        &ResalePolicy {
+            enabled: true,
+            max_price_bps: 11000,
+            royalty_bps: 1000,
+        },
+    );
+
+    assert_eq!(
+        client.try_list_for_resale(&seller, &token_id, &111),
+        Err(Ok(TicketError::ResalePriceTooHigh))
+    );
+    assert_eq!(
+        client.try_list_for_resale(&buyer, &token_id, &110),
+        Err(Ok(TicketError::NotTicketOwner))
+    );
+    client.list_for_resale(&seller, &token_id, &110);
+    assert_last_event(
+        &e,
+        &client.address,
+        TicketListed {
+            tier_symbol: tier_sym.clone(),
+            seller: seller.clone(),
+            token_id,
+            price: 110,
+        },
+    );
+
+    client.buy_resale(&buyer, &token_id);
+    assert_eq!(client.owner_of(&token_id), buyer);
+    assert_eq!(token.balance(&buyer), 890);
+    assert_eq!(token.balance(&seller), 999);
+    // Original price plus the 11 royalty
+    assert_eq!(client.escrow_balance(&token.address), 111);
+    assert_eq!(
+        client.try_get_listing(&token_id),
+        Err(Ok(TicketError::ListingNotFound))
+    );
+
+    // Plain transfers remain disabled
+    assert!(client.try_transfer(&buyer, &seller, &token_id).is_err());
+
+    // A refund goes to the new holder at the original price
+    client.refund(&buyer, &token_id);
+    assert_eq!(token.balance(&buyer), 990);
+    assert_eq!(client.escrow_balance(&token.address), 11);
+}
+
+#[test]
+fn test_upgrade_ticket() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let general = Symbol::new(&e, "GEN");
+    let vip = Symbol::new(&e, "VIP");
+    for (tier_sym, price, supply) in [(&general, 100, 10), (&vip, 250, 1)] {
+        client.add_tier(
+            &admin,
+            tier_sym,
+            &String::from_str(&e, "Tier"),
+            &price,
+            &supply,
+            &PricingCurve::Flat,
+        );
+        client.set_accepted_token(&admin, tier_sym, &token.address, &price);
+    }
+
+    let token_id = client.purchase(&buyer, &token.address, &general);
+    let other_id = client.purchase(&buyer, &token.address, &general);
+    assert_eq!(
+        client.try_upgrade_ticket(&buyer, &token_id, &general),
+        Err(Ok(TicketError::InvalidUpgrade))
+    );
+
+    // Allowlisted tiers can only be bought with a proof
+    let root = BytesN::from_array(&e, &[1; 32]);
+    client.set_allowlist_root(&admin, &vip, &Some(root));
+    assert_eq!(
+        client.try_upgrade_ticket(&buyer, &token_id, &vip),
+        Err(Ok(TicketError::AllowlistRequired))
+    );
+    client.set_allowlist_root(&admin, &vip, &None);
+
+    client.upgrade_ticket(&buyer, &token_id, &vip);
+    assert_last_event(
+        &e,
+        &client.address,
+        TicketUpgraded {
+            tier_symbol: vip.clone(),
+            owner: buyer.clone(),
+            token_id,
+            from_tier: general.clone(),
+            amount_paid: 150,
+        },
+    );
+    assert_eq!(token.balance(&buyer), 650);
+    assert_eq!(client.get_tier(&general).minted, 1);
+    assert_eq!(client.get_tier(&vip).minted, 1);
+
+    let ticket = client.get_ticket(&token_id);
+    assert_eq!(ticket.tier_symbol, vip);
+    assert_eq!(ticket.price_paid, 250);
+    assert_eq!(ticket.upgrades.len(), 1);
+
+    // The target tier is now full
+    assert_eq!(
+        client.try_upgrade_ticket(&buyer, &other_id, &vip),
+        Err(Ok(TicketError::TierSoldOut))
+    );
+
+    // Refunds cover everything paid including the upgrade
+    client.refund(&buyer, &token_id);
+    assert_eq!(token.balance(&buyer), 900);
+}
+
+#[test]
+fn test_upgrade_gifted_ticket() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let payer = Address::generate(&e);
+    let friend = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&payer, &1_000);
+    token.mint(&friend, &1_000);
+
+    let general = Symbol::new(&e, "GEN");
+    let vip = Symbol::new(&e, "VIP");
+    for (tier_sym, price) in [(&general, 100), (&vip, 250)] {
+        client.add_tier(
+            &admin,
+            tier_sym,
+            &String::from_str(&e, "Tier"),
+            &price,
+            &10,
+            &PricingCurve::Flat,
+        );
+        client.set_accepted_token(&admin, tier_sym, &token.address, &price);
+    }
+
+    // The refund of a gifted ticket belongs to the payer, so the recipient
+    // cannot pay into it
+    let token_id = client.purchase_for(&payer, &friend, &token.address, &general);
+    assert_eq!(
+        client.try_upgrade_ticket(&friend, &token_id, &vip),
+        Err(Ok(TicketError::GiftedTicket))
+    );
+    assert_eq!(token.balance(&friend), 1_000);
+
+    client.refund(&friend, &token_id);
+    assert_eq!(token.balance(&payer), 1_000);
+    assert_eq!(token.balance(&friend), 1_000);
+}
+
+#[test]
+fn test_purchase_with_promo_code() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+    token.mint(&other, &1_000);
+
+    let general = Symbol::new(&e, "GEN");
+    let vip = Symbol::new(&e, "VIP");
+    for tier_sym in [&general, &vip] {
+        client.add_tier(
+            &admin,
+            tier_sym,
+            &String::from_str(&e, "Tier"),
+            &100,
+            &10,
+            &PricingCurve::Flat,
+        );
+        client.set_accepted_token(&admin, tier_sym, &token.address, &100);
+    }
+
+    let code = String::from_str(&e, "EARLY25");
+    let code_hash: BytesN<32> = e.crypto().sha256(&code.to_bytes()).into();
+    let promo = PromoCode {
+        discount: Discount::Percent(2500),
+        tiers: vec![&e, general.clone()],
+        max_uses: 2,
+        uses: 0,
+        max_per_wallet: 1,
+        expires_at: e.ledger().timestamp() + 1_000,
+    };
+
+    assert_eq!(
+        client.try_purchase_with_code(&buyer, &token.address, &general, &code),
+        Err(Ok(TicketError::PromoCodeNotFound))
+    );
+    client.set_promo_code(&admin, &code_hash, &promo);
+
+    assert_eq!(
+        client.try_purchase_with_code(&buyer, &token.address, &vip, &code),
+        Err(Ok(TicketError::PromoCodeNotApplicable))
+    );
+
+    let token_id = client.purchase_with_code(&buyer, &token.address, &general, &code);
+    assert_last_event(
+        &e,
+        &client.address,
+        PromoCodeRedeemed {
+            tier_symbol: general.clone(),
+            buyer: buyer.clone(),
+            code_hash: code_hash.clone(),
+            token_id,
+            discount: 25,
+        },
+    );
+    assert_eq!(token.balance(&buyer), 925);
+    let ticket = client.get_ticket(&token_id);
+    assert_eq!(ticket.price_paid, 75);
+    assert_eq!(ticket.promo_code, Some(code_hash.clone()));
+
+    // One use per wallet, two in total
+    assert_eq!(
+        client.try_purchase_with_code(&buyer, &token.address, &general, &code),
+        Err(Ok(TicketError::PromoCodeExhausted))
+    );
+    client.purchase_with_code(&other, &token.address, &general, &code);
+    assert_eq!(client.get_promo_code(&code_hash).uses, 2);
+
+    // Replacing the code, e.g. to extend it, does not reset its uses
+    let extended = PromoCode {
+        expires_at: e.ledger().timestamp() + 5_000,
+        ..promo.clone()
+    };
+    client.set_promo_code(&admin, &code_hash, &extended);
+    assert_eq!(client.get_promo_code(&code_hash).uses, 2);
+    assert_eq!(
+        client.try_purchase_with_code(&Address::generate(&e), &token.address, &general, &code),
+        Err(Ok(TicketError::PromoCodeExhausted))
+    );
+
+    let fixed = String::from_str(&e, "FREE");
+    let fixed_hash: BytesN<32> = e.crypto().sha256(&fixed.to_bytes()).into();
+    client.set_promo_code(
+        &admin,
+        &fixed_hash,
+        &PromoCode {
+            discount: Discount::Fixed(500),
+            tiers: vec![&e],
+            max_uses: 0,
+            uses: 0,
+            max_per_wallet: 0,
+            expires_at: 0,
+        },
+    );
+    // A fixed discount never takes the price below zero
+    client.purchase_with_code(&buyer, &token.address, &vip, &fixed);
+    assert_eq!(token.balance(&buyer), 925);
+
+    e.ledger().with_mut(|li| li.timestamp += 1_001);
+    client.set_promo_code(&admin, &code_hash, &promo);
+    assert_eq!(
+        client.try_purchase_with_code(&buyer, &token.address, &general, &code),
+        Err(Ok(TicketError::PromoCodeExpired))
+    );
+}
+
+#[test]
+fn test_token_uri_reflects_ticket_state() {
+    let e = Env::default();
+    e.mock_all_auths();
+    e.ledger().with_mut(|li| li.timestamp = 1_700_000_000);
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+
+    let tier_sym = Symbol::new(&e, "VIP");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "VIP"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+    let refunded_id = client.purchase(&buyer, &token.address, &tier_sym);
+    let token_id = client.purchase(&buyer, &token.address, &tier_sym);
+    let expected = |base: &str, id: u32, status: &str| {
+        let uri = std::format!("{base}{id}?tier=VIP&status={status}&purchased=1700000000");
+        String::from_str(&e, &uri)
+    };
+
+    assert_eq!(
+        client.token_uri(&token_id),
+        expected("https://example.com", token_id, "valid")
+    );
+
+    let uri = String::from_str(&e, "https://cdn.example.com/vip/");
+    client.set_tier_metadata_uri(&admin, &tier_sym, &Some(uri));
+    client.check_in(&admin, &token_id);
+    assert_eq!(
+        client.token_uri(&token_id),
+        expected("https://cdn.example.com/vip/", token_id, "checked_in")
+    );
+
+    client.issue_refund(&admin, &refunded_id);
+    assert_eq!(
+        client.token_uri(&refunded_id),
+        expected("https://cdn.example.com/vip/", refunded_id, "invalid")
+    );
+
+    let too_long = String::from_bytes(&e, &[b'a'; 201]);
+    assert_eq!(
+        client.try_set_tier_metadata_uri(&admin, &tier_sym, &Some(too_long)),
+        Err(Ok(TicketError::MetadataUriTooLong))
+    );
+}
+
+#[test]
+fn test_reserved_seating() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+    token.mint(&other, &1_000);
+
+    let tier_sym = Symbol::new(&e, "ORCH");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "Orchestra"),
+        &100,
+        &3,
+        &PricingCurve::Flat,
+    );
+    client.set_accepted_token(&admin, &tier_sym, &token.address, &100);
+
+    let section = Symbol::new(&e, "A");
+    let seat = |row: u32, number: u32| Seat {
+        section: section.clone(),
+        row,
+        number,
+    };
+    client.add_seats(
+        &admin,
+        &tier_sym,
+        &vec![&e, seat(1, 1), seat(1, 2), seat(2, 1)],
+    );
+    assert_eq!(
+        client.try_add_seats(&admin, &tier_sym, &vec![&e, seat(1, 1)]),
+        Err(Ok(TicketError::SeatAlreadyExists))
+    );
+    assert_eq!(client.get_sections(&tier_sym), vec![&e, section.clone()]);
+
+    // Seated tiers only sell specific seats
+    assert_eq!(
+        client.try_purchase(&buyer, &token.address, &tier_sym),
+        Err(Ok(TicketError::SeatSelectionRequired))
+    );
+    assert_eq!(
+        client.try_purchase_seat(&buyer, &token.address, &tier_sym, &seat(9, 9)),
+        Err(Ok(TicketError::SeatNotFound))
+    );
+
+    let token_id = client.purchase_seat(&buyer, &token.address, &tier_sym, &seat(1, 2));
+    assert_last_event(
+        &e,
+        &client.address,
+        SeatAssigned {
+            tier_symbol: tier_sym.clone(),
+            owner: buyer.clone(),
+            token_id,
+            seat: seat(1, 2),
+        },
+    );
+    assert_eq!(client.get_ticket(&token_id).seat, Some(seat(1, 2)));
+    assert_eq!(client.seat_holder(&tier_sym, &seat(1, 2)), Some(token_id));
+    assert_eq!(
+        client.try_purchase_seat(&other, &token.address, &tier_sym, &seat(1, 2)),
+        Err(Ok(TicketError::SeatTaken))
+    );
+    assert_eq!(
+        client.available_seats(&tier_sym, &section, &0, &10),
+        vec![&e, seat(1, 1), seat(2, 1)]
+    );
+    // Pages cover seat slots, sold or not
+    assert_eq!(
+        client.available_seats(&tier_sym, &section, &1, &2),
+        vec![&e, seat(2, 1)]
+    );
+
+    // Refunding frees the seat and its supply for someone else
+    client.purchase_seat(&other, &token.address, &tier_sym, &seat(1, 1));
+    client.purchase_seat(&other, &token.address, &tier_sym, &seat(2, 1));
+    client.refund(&buyer, &token_id);
+    assert_eq!(client.seat_holder(&tier_sym, &seat(1, 2)), None);
+    assert_eq!(
+        client.available_seats(&tier_sym, &section, &0, &10),
+        vec![&e, seat(1, 2)]
+    );
+    client.purchase_seat(&other, &token.address, &tier_sym, &seat(1, 2));
+
+    // Seated tiers sell by seat and allowlisted tiers by proof, never both
+    let root = BytesN::from_array(&e, &[1; 32]);
+    assert_eq!(
+        client.try_set_allowlist_root(&admin, &tier_sym, &Some(root.clone())),
+        Err(Ok(TicketError::SeatedAllowlist))
+    );
+    let balcony = Symbol::new(&e, "BALC");
+    client.add_tier(
+        &admin,
+        &balcony,
+        &String::from_str(&e, "Balcony"),
+        &100,
+        &3,
+        &PricingCurve::Flat,
+    );
+    client.set_allowlist_root(&admin, &balcony, &Some(root));
+    assert_eq!(
+        client.try_add_seats(&admin, &balcony, &vec![&e, seat(1, 1)]),
+        Err(Ok(TicketError::SeatedAllowlist))
+    );
+}
+
+#[test]
+fn test_paginated_indexes() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let other = Address::generate(&e);
+    let client = create_contract(&e, &admin);
+    let token = create_token(&e, &admin);
+    token.mint(&buyer, &1_000);
+    token.mint(&other, &1_000);
+
+    let general = Symbol::new(&e, "GEN");
+    let vip = Symbol::new(&e, "VIP");
+    let backstage = Symbol::new(&e, "BACK");
+    for tier_sym in [&general, &vip, &backstage] {
+        client.add_tier(
+            &admin,
+            tier_sym,
+            &String::from_str(&e, "Tier"),
+            &100,
+            &10,
+            &PricingCurve::Flat,
+        );
+        client.set_accepted_token(&admin, tier_sym, &token.address, &100);
+    }
+
+    assert_eq!(
+        client.list_tiers(&0, &10),
+        vec![&e, general.clone(), vip.clone(), backstage.clone()]
+    );
+    assert_eq!(client.list_tiers(&1, &1), vec![&e, vip.clone()]);
+    assert_eq!(client.list_tiers(&5, &10), vec![&e]);
+
+    let first = client.purchase(&buyer, &token.address, &general);
+    let second = client.purchase(&other, &token.address, &general);
+    let third = client.purchase(&buyer, &token.address, &vip);
+    let fourth = client.purchase(&buyer, &token.address, &general);
+
+    assert_eq!(
+        client.tickets_of(&buyer, &0, &10),
+        vec![&e, first, third, fourth]
+    );
+    assert_eq!(client.tickets_of(&buyer, &1, &1), vec![&e, third]);
+    assert_eq!(client.tickets_of(&buyer, &3, &10), vec![&e]);
+    assert_eq!(
+        client.tickets_by_tier(&general, &0, &10),
+        vec![&e, first, second, fourth]
+    );
+
+    // Burns and upgrades keep the indexes in step, moving the last ticket
+    // into the freed slot
+    client.refund(&buyer, &first);
+    assert_eq!(client.tickets_of(&buyer, &0, &10), vec![&e, fourth, third]);
+    assert_eq!(
+        client.tickets_by_tier(&general, &0, &10),
+        vec![&e, fourth, second]
+    );
+    client.upgrade_ticket(&other, &second, &vip);
+    assert_eq!(client.tickets_by_tier(&general, &0, &10), vec![&e, fourth]);
+    assert_eq!(
+        client.tickets_by_tier(&vip, &0, &10),
+        vec![&e, third, second]
+    );
+}
+
+#[test]
+fn test_ticket_ttl_covers_event_and_settlement() {
+    let e = Env::default();
+    e.mock_all_auths();
+
+    let admin = Address::generate(&e);
+    let buyer = Address::generate(&e);
+    let contract_id = e.register_contract(None, SoulboundTicketContract);
+    let client = SoulboundTicketContractClient::new(&e, &contract_id);
+
+    // An event half a year out outlives the default persistent TTL
+    let now = e.ledger().timestamp();
+    let end_time = now + 180 * 24 * 60 * 60;
+    client.initialize(
+        &admin,
+        &String::from_str(&e, "EventTicket"),
+        &String::from_str(&e, "TKT"),
+        &String::from_str(&e, "https://example.com"),
+        &(end_time - 1),
+        &end_time,
+        &now,
+    );
+    let expected_ttl = ((end_time + DISPUTE_WINDOW - now) / LEDGER_SECONDS) as u32;
+    assert!(expected_ttl > TTL_PERSISTENT);
+
+    let tier_sym = Symbol::new(&e, "GEN");
+    client.add_tier(
+        &admin,
+        &tier_sym,
+        &String::from_str(&e, "General"),
+        &100,
+        &10,
+        &PricingCurve::Flat,
+    );
+    client.batch_mint(&admin, &buyer, &tier_sym, &1);
+    let token_id = client.tickets_of(&buyer, &0, &1).get(0).unwrap();
+
+    let entry_ttl =
+        |key: Val| e.as_contract(&contract_id, || e.storage().persistent().get_ttl(&key));
+    let ticket_entries: [Val; 7] = [
+        DataKey::Ticket(token_id).into_val(&e),
+        DataKey::OwnerTicket(buyer.clone(), 0).into_val(&e),
+        DataKey::OwnerTicketCount(buyer.clone()).into_val(&e),
+        DataKey::TierTicket(tier_sym.clone(), 0).into_val(&e),
+        DataKey::TierTicketCount(tier_sym.clone()).into_val(&e),
+        NFTStorageKey::Owner(token_id).into_val(&e),
+        NFTStorageKey::Balance(buyer.clone()).into_val(&e),
+    ];
+    for key in ticket_entries {
+        assert_eq!(entry_ttl(key), expected_ttl);
+    }
+    assert_eq!(entry_ttl(DataKey::TierList.into_val(&e)), expected_ttl);
+
+    // Anyone can top the ticket, its ownership and its index entries back up
+    // later on
+    e.ledger().with_mut(|li| li.sequence_number += 100_000);
+    assert_eq!(
+        entry_ttl(DataKey::Ticket(token_id).into_val(&e)),
+        expected_ttl - 100_000
+    );
+    client.bump_ticket(&token_id);
+    for key in ticket_entries {
+        assert_eq!(entry_ttl(key), expected_ttl);
+    }
+    assert_eq!(
+        client.try_bump_ticket(&(token_id + 1)),
+        Err(Ok(TicketError::TicketNotFound))
+    );
+}